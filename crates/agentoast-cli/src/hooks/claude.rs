@@ -55,6 +55,7 @@ pub fn run() -> Result<(), String> {
             metadata: &metadata,
             repo_name: &repo_name,
             force_focus,
+            channels: &hook_config.channels,
         },
     )
 }
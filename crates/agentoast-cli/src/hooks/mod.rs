@@ -5,6 +5,7 @@ pub mod opencode;
 use std::collections::HashMap;
 use std::path::Path;
 
+use agentoast_shared::notifier::{self, NotificationPayload as DeliveryPayload};
 use agentoast_shared::{config, db, models::IconType};
 use serde::Serialize;
 
@@ -145,26 +146,47 @@ pub struct NotificationPayload<'a> {
     pub metadata: &'a HashMap<String, String>,
     pub repo_name: &'a str,
     pub force_focus: bool,
+    /// The calling agent's `channels` config (e.g. `notification.agents.codex.channels`),
+    /// forwarded to [`notifier::backends_for_channels`] so hook-sourced notifications fan
+    /// out to the same delivery backends as the rest of the app.
+    pub channels: &'a [String],
 }
 
-/// Opens a DB connection and inserts a notification
+/// Opens a DB connection, inserts a notification, and fans it out to the
+/// configured delivery backends. The DB write happens first so the toast
+/// panel and history are never missing a notification a slow or failing
+/// delivery backend held up.
 pub fn insert_notification(ctx: &HookContext, p: &NotificationPayload) -> Result<(), String> {
     let db_path = config::db_path();
     let conn = db::open_reader(&db_path).map_err(|e| format!("Failed to open database: {}", e))?;
     db::insert_notification(
         &conn,
-        &db::NotificationInput {
+        p.badge,
+        p.body,
+        p.badge_color,
+        p.icon,
+        p.metadata,
+        p.repo_name,
+        &ctx.tmux_pane,
+        &ctx.terminal_bundle_id,
+        p.force_focus,
+    )
+    .map(|_| ())
+    .map_err(|e| format!("Failed to insert notification: {}", e))?;
+
+    let backends =
+        notifier::backends_for_channels(&config::load_config().notification.delivery, p.channels);
+    notifier::dispatch(
+        &backends,
+        &DeliveryPayload {
             badge: p.badge,
             body: p.body,
             badge_color: p.badge_color,
             icon: p.icon,
-            metadata: p.metadata,
-            repo: p.repo_name,
-            tmux_pane: &ctx.tmux_pane,
-            terminal_bundle_id: &ctx.terminal_bundle_id,
+            repo_name: p.repo_name,
             force_focus: p.force_focus,
         },
-    )
-    .map(|_| ())
-    .map_err(|e| format!("Failed to insert notification: {}", e))
+    );
+
+    Ok(())
 }
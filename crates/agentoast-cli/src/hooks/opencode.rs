@@ -63,6 +63,7 @@ pub fn run(json_arg: &str) -> Result<(), String> {
             metadata: &metadata,
             repo_name: &repo_name,
             force_focus,
+            channels: &hook_config.channels,
         },
     )
 }
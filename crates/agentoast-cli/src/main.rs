@@ -1,13 +1,17 @@
 use std::collections::HashMap;
 use std::io::Read;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use agentoast_shared::config;
 use agentoast_shared::db;
 use agentoast_shared::models::IconType;
+use agentoast_shared::notifier::{self, NotificationPayload};
 use clap::{Parser, Subcommand};
 use serde::{Deserialize, Serialize};
 
+mod repl;
+mod tui;
+
 #[derive(Parser)]
 #[command(name = "agentoast", about = "Agentoast - CLI notification tool")]
 struct Cli {
@@ -55,6 +59,11 @@ enum Commands {
         /// Metadata key=value pairs (can be specified multiple times)
         #[arg(short = 'm', long = "meta", value_name = "KEY=VALUE")]
         meta: Vec<String>,
+
+        /// Only enqueue the notification in the DB, skipping delivery backends
+        /// (desktop/email/webhook/APNs)
+        #[arg(long)]
+        no_deliver: bool,
     },
 
     /// Handle hook events from AI coding agents
@@ -70,8 +79,45 @@ enum Commands {
         limit: i64,
     },
 
+    /// Snooze a notification, hiding it until it's re-surfaced later
+    Snooze {
+        /// Notification id (see `agentoast list`)
+        id: i64,
+
+        /// Human interval string, e.g. "15m", "2h30m", "1d" (default: from
+        /// `[notification.snooze].default_interval`)
+        #[arg(long)]
+        r#for: Option<String>,
+    },
+
     /// Open config file in editor
     Config,
+
+    /// Run an HTTP listener that accepts signed hook events from remote/containerized agents
+    Serve {
+        /// Address to bind the HTTP listener to
+        #[arg(long, default_value = "127.0.0.1:4317")]
+        addr: String,
+
+        /// Name of the environment variable holding the shared HMAC secret
+        #[arg(long, default_value = "AGENTOAST_SERVE_SECRET")]
+        secret_env: String,
+    },
+
+    /// Run a loopback HTTP API exposing notifications to other tooling
+    /// (editors, tmux status bars, scripts), per `[admin]` in config
+    Admin,
+
+    /// Open an interactive TUI inbox for browsing and acting on notifications
+    Watch {
+        /// How often to poll the database for new notifications, in milliseconds
+        #[arg(long, default_value_t = 1000)]
+        interval_ms: u64,
+    },
+
+    /// Open an interactive REPL for inspecting and overriding the status of
+    /// monitored tmux panes, with command history and tab completion
+    Repl,
 }
 
 #[derive(Subcommand)]
@@ -88,6 +134,27 @@ enum HookAgent {
         /// JSON payload containing event type, properties, and directory
         json: String,
     },
+    /// Handle an inbound webhook event (e.g. a GitHub push), reads the raw
+    /// request body from stdin
+    Webhook {
+        /// Name of the configured secret to verify against (see
+        /// `[notification.agents.webhook.secrets]`)
+        #[arg(long)]
+        secret_name: String,
+
+        /// Signature header value, e.g. "sha256=<hex>" (X-Hub-Signature-256 style)
+        #[arg(long)]
+        signature: String,
+    },
+    /// Handle a hook event from a config-declared agent (see
+    /// `[notification.agents.generic.<name>]`), reads JSON from the CLI argument
+    Generic {
+        /// Name of the agent as declared under `notification.agents.generic`
+        name: String,
+
+        /// JSON payload for this event
+        json: String,
+    },
 }
 
 #[derive(Deserialize)]
@@ -98,15 +165,6 @@ struct ClaudeHookData {
     message: Option<String>,
 }
 
-#[derive(Deserialize)]
-struct CodexHookData {
-    #[serde(rename = "type")]
-    event_type: String,
-    cwd: Option<String>,
-    #[serde(rename = "last-assistant-message")]
-    last_assistant_message: Option<String>,
-}
-
 #[derive(Deserialize)]
 struct OpenCodeHookData {
     #[serde(rename = "type")]
@@ -128,6 +186,24 @@ struct GitInfo {
     branch_name: String,
 }
 
+/// Minimal slice of a GitHub-style push webhook payload.
+#[derive(Deserialize)]
+struct WebhookPushPayload {
+    after: String,
+    repository: WebhookRepository,
+    head_commit: Option<WebhookCommit>,
+}
+
+#[derive(Deserialize)]
+struct WebhookRepository {
+    full_name: String,
+}
+
+#[derive(Deserialize)]
+struct WebhookCommit {
+    message: String,
+}
+
 fn parse_metadata(meta_args: &[String]) -> HashMap<String, String> {
     let mut metadata = HashMap::new();
     for entry in meta_args {
@@ -143,7 +219,36 @@ fn parse_metadata(meta_args: &[String]) -> HashMap<String, String> {
     metadata
 }
 
+/// Resolves a dot-separated path (e.g. "status.type") against nested JSON.
+/// Used by the OpenCode idle check and the config-driven generic hook.
+fn resolve_json_path<'a>(value: &'a serde_json::Value, path: &str) -> Option<&'a serde_json::Value> {
+    path.split('.').try_fold(value, |v, key| v.get(key))
+}
+
+/// Resolves the repo name/branch for a notification's `cwd`. `AGENTOAST_REPO_NAME`
+/// is checked first (and skips the git lookups for the repo name; the branch
+/// is still read via `git branch --show-current`) so users can pin a stable
+/// name regardless of where the hook actually runs from.
 fn get_git_info(cwd: &Path) -> GitInfo {
+    if let Ok(repo_name) = std::env::var("AGENTOAST_REPO_NAME") {
+        if !repo_name.is_empty() {
+            let branch_name = std::process::Command::new("git")
+                .args(["branch", "--show-current"])
+                .current_dir(cwd)
+                .stdout(std::process::Stdio::piped())
+                .stderr(std::process::Stdio::null())
+                .output()
+                .ok()
+                .filter(|o| o.status.success())
+                .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+                .unwrap_or_default();
+            return GitInfo {
+                repo_name,
+                branch_name,
+            };
+        }
+    }
+
     let mut repo_name = String::new();
     let mut branch_name = String::new();
 
@@ -177,10 +282,25 @@ fn get_git_info(cwd: &Path) -> GitInfo {
         }
 
         if repo_name.is_empty() {
-            repo_name = cwd
-                .file_name()
+            // No origin: fall back to the repo root's basename rather than
+            // cwd's, so a linked worktree (whose cwd is its own directory,
+            // not the main repo's) still reports the main project's name.
+            // `--git-common-dir` resolves to the shared `.git` dir even from
+            // inside a worktree; `--show-toplevel` covers the rest (e.g.
+            // older git) when that lookup fails.
+            let repo_root = resolve_git_rev_parse_path(cwd, "--git-common-dir")
+                .and_then(|d| d.parent().map(|p| p.to_path_buf()))
+                .or_else(|| resolve_git_rev_parse_path(cwd, "--show-toplevel"));
+
+            repo_name = repo_root
+                .as_deref()
+                .and_then(|p| p.file_name())
                 .map(|n| n.to_string_lossy().to_string())
-                .unwrap_or_default();
+                .unwrap_or_else(|| {
+                    cwd.file_name()
+                        .map(|n| n.to_string_lossy().to_string())
+                        .unwrap_or_default()
+                });
         }
 
         if let Ok(output) = std::process::Command::new("git")
@@ -207,14 +327,45 @@ fn get_git_info(cwd: &Path) -> GitInfo {
     }
 }
 
+/// Runs `git rev-parse <flag>` in `cwd` and resolves the result to an
+/// absolute path (the output is relative to `cwd` unless `flag` already
+/// yields an absolute path, e.g. `--git-common-dir` from inside a worktree).
+fn resolve_git_rev_parse_path(cwd: &Path, flag: &str) -> Option<PathBuf> {
+    let output = std::process::Command::new("git")
+        .args(["rev-parse", flag])
+        .current_dir(cwd)
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::null())
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let raw = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if raw.is_empty() {
+        return None;
+    }
+
+    let candidate = cwd.join(&raw);
+    Some(candidate.canonicalize().unwrap_or(candidate))
+}
+
 fn run_claude_hook() -> Result<(), String> {
     let mut input = String::new();
     std::io::stdin()
         .read_to_string(&mut input)
         .map_err(|e| format!("Failed to read stdin: {}", e))?;
 
+    run_claude_hook_from_input(&input)
+}
+
+/// Same as [`run_claude_hook`], but takes the hook payload directly instead of
+/// reading stdin — shared by the local CLI path and `Commands::Serve`.
+fn run_claude_hook_from_input(input: &str) -> Result<(), String> {
     let data: ClaudeHookData =
-        serde_json::from_str(&input).map_err(|e| format!("Failed to parse JSON: {}", e))?;
+        serde_json::from_str(input).map_err(|e| format!("Failed to parse JSON: {}", e))?;
 
     let event_key = data
         .notification_type
@@ -253,7 +404,7 @@ fn run_claude_hook() -> Result<(), String> {
     let db_path = config::db_path();
     let conn = db::open_reader(&db_path).map_err(|e| format!("Failed to open database: {}", e))?;
 
-    db::insert_notification(
+    db::insert_notification_deduped(
         &conn,
         badge,
         body,
@@ -264,9 +415,27 @@ fn run_claude_hook() -> Result<(), String> {
         &tmux_pane,
         &terminal_bundle_id,
         force_focus,
+        hook_config.dedup.enabled.then_some(hook_config.dedup.window_secs),
+        hook_config.dedup.mode,
     )
     .map_err(|e| format!("Failed to insert notification: {}", e))?;
 
+    let backends = notifier::backends_for_channels(
+        &config::load_config().notification.delivery,
+        &hook_config.channels,
+    );
+    notifier::dispatch(
+        &backends,
+        &NotificationPayload {
+            badge,
+            body,
+            badge_color,
+            icon: &IconType::ClaudeCode,
+            repo_name: &repo_name,
+            force_focus,
+        },
+    );
+
     Ok(())
 }
 
@@ -287,26 +456,50 @@ fn truncate_body(msg: &str) -> String {
     truncated
 }
 
-fn run_codex_hook(json_arg: &str) -> Result<(), String> {
-    let data: CodexHookData =
+fn handle_codex_hook(json: &str) {
+    let result = match run_codex_hook(json) {
+        Ok(()) => HookResult {
+            success: true,
+            error: None,
+        },
+        Err(e) => HookResult {
+            success: false,
+            error: Some(e),
+        },
+    };
+
+    println!(
+        "{}",
+        serde_json::to_string(&result).unwrap_or_else(|_| r#"{"success":false}"#.to_string())
+    );
+}
+
+fn run_opencode_hook(json_arg: &str) -> Result<(), String> {
+    let data: OpenCodeHookData =
         serde_json::from_str(json_arg).map_err(|e| format!("Failed to parse JSON: {}", e))?;
 
-    let hook_config = config::load_config().notification.agents.codex;
+    let hook_config = config::load_config().notification.agents.opencode;
 
     if !hook_config.events.iter().any(|e| e == &data.event_type) {
         return Ok(());
     }
 
-    let badge = "Stop";
-    let badge_color = "green";
-    let body = if hook_config.include_body {
-        data.last_assistant_message
-            .as_deref()
-            .map(truncate_body)
-            .unwrap_or_default()
-    } else {
-        String::new()
+    // For session.status, only notify on idle sub-type
+    if data.event_type == "session.status" {
+        let is_idle = resolve_json_path(&data.properties, "status.type").and_then(|t| t.as_str())
+            == Some("idle");
+        if !is_idle {
+            return Ok(());
+        }
+    }
+
+    let (badge, badge_color) = match data.event_type.as_str() {
+        "session.status" => ("Stop", "green"),
+        "session.error" => ("Error", "red"),
+        "permission.asked" => ("Permission", "blue"),
+        _ => ("Notification", "gray"),
     };
+
     let force_focus = hook_config
         .focus_events
         .iter()
@@ -315,8 +508,8 @@ fn run_codex_hook(json_arg: &str) -> Result<(), String> {
     let mut metadata = HashMap::new();
 
     let repo_name;
-    if let Some(ref cwd_str) = data.cwd {
-        let cwd = Path::new(cwd_str);
+    if let Some(ref dir) = data.directory {
+        let cwd = Path::new(dir);
         let git_info = get_git_info(cwd);
         repo_name = git_info.repo_name;
         if !git_info.branch_name.is_empty() {
@@ -332,25 +525,43 @@ fn run_codex_hook(json_arg: &str) -> Result<(), String> {
     let db_path = config::db_path();
     let conn = db::open_reader(&db_path).map_err(|e| format!("Failed to open database: {}", e))?;
 
-    db::insert_notification(
+    db::insert_notification_deduped(
         &conn,
         badge,
-        &body,
+        "",
         badge_color,
-        &IconType::Codex,
+        &IconType::OpenCode,
         &metadata,
         &repo_name,
         &tmux_pane,
         &terminal_bundle_id,
         force_focus,
+        hook_config.dedup.enabled.then_some(hook_config.dedup.window_secs),
+        hook_config.dedup.mode,
     )
     .map_err(|e| format!("Failed to insert notification: {}", e))?;
 
+    let backends = notifier::backends_for_channels(
+        &config::load_config().notification.delivery,
+        &hook_config.channels,
+    );
+    notifier::dispatch(
+        &backends,
+        &NotificationPayload {
+            badge,
+            body: "",
+            badge_color,
+            icon: &IconType::OpenCode,
+            repo_name: &repo_name,
+            force_focus,
+        },
+    );
+
     Ok(())
 }
 
-fn handle_codex_hook(json: &str) {
-    let result = match run_codex_hook(json) {
+fn handle_opencode_hook(json: &str) {
+    let result = match run_opencode_hook(json) {
         Ok(()) => HookResult {
             success: true,
             error: None,
@@ -367,46 +578,251 @@ fn handle_codex_hook(json: &str) {
     );
 }
 
-fn run_opencode_hook(json_arg: &str) -> Result<(), String> {
-    let data: OpenCodeHookData =
-        serde_json::from_str(json_arg).map_err(|e| format!("Failed to parse JSON: {}", e))?;
+/// Verifies an `X-Hub-Signature-256`-style header: `HMAC-SHA256(secret, body)`,
+/// hex-encoded, optionally prefixed with `sha256=`. Comparison is constant-time
+/// to avoid leaking the expected signature through timing.
+fn verify_webhook_signature(secret: &str, body: &[u8], signature_header: &str) -> bool {
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
 
-    let hook_config = config::load_config().notification.agents.opencode;
+    let expected_hex = signature_header
+        .strip_prefix("sha256=")
+        .unwrap_or(signature_header);
 
-    if !hook_config.events.iter().any(|e| e == &data.event_type) {
-        return Ok(());
+    let mut mac = match Hmac::<Sha256>::new_from_slice(secret.as_bytes()) {
+        Ok(mac) => mac,
+        Err(_) => return false,
+    };
+    mac.update(body);
+    let computed_hex = hex::encode(mac.finalize().into_bytes());
+
+    constant_time_eq(computed_hex.as_bytes(), expected_hex.as_bytes())
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
     }
+    a.iter()
+        .zip(b.iter())
+        .fold(0u8, |acc, (x, y)| acc | (x ^ y))
+        == 0
+}
 
-    // For session.status, only notify on idle sub-type
-    if data.event_type == "session.status" {
-        let is_idle = data
-            .properties
-            .get("status")
-            .and_then(|s| s.get("type"))
-            .and_then(|t| t.as_str())
-            == Some("idle");
-        if !is_idle {
-            return Ok(());
-        }
+fn run_webhook_hook(secret_name: &str, signature: &str, raw_body: &[u8]) -> Result<(), String> {
+    let hook_config = config::load_config().notification.agents.webhook;
+
+    let secret = hook_config
+        .secrets
+        .get(secret_name)
+        .ok_or_else(|| format!("No secret configured for webhook sender '{}'", secret_name))?;
+
+    if !verify_webhook_signature(secret, raw_body, signature) {
+        return Err("Webhook signature verification failed".to_string());
     }
 
-    let (badge, badge_color) = match data.event_type.as_str() {
-        "session.status" => ("Stop", "green"),
-        "session.error" => ("Error", "red"),
-        "permission.asked" => ("Permission", "blue"),
-        _ => ("Notification", "gray"),
+    let data: WebhookPushPayload =
+        serde_json::from_slice(raw_body).map_err(|e| format!("Failed to parse JSON: {}", e))?;
+
+    let badge = "Push";
+    let badge_color = "blue";
+    let body = data
+        .head_commit
+        .map(|c| truncate_body(&c.message))
+        .unwrap_or_default();
+
+    let mut metadata = HashMap::new();
+    metadata.insert("sha".to_string(), data.after);
+
+    let repo_name = data.repository.full_name;
+    let tmux_pane = String::new();
+    let terminal_bundle_id = String::new();
+
+    let db_path = config::db_path();
+    let conn = db::open_reader(&db_path).map_err(|e| format!("Failed to open database: {}", e))?;
+
+    db::insert_notification_deduped(
+        &conn,
+        badge,
+        &body,
+        badge_color,
+        &IconType::Agentoast,
+        &metadata,
+        &repo_name,
+        &tmux_pane,
+        &terminal_bundle_id,
+        false,
+        None,
+        agentoast_shared::models::DedupMode::Skip,
+    )
+    .map_err(|e| format!("Failed to insert notification: {}", e))?;
+
+    let backends = notifier::backends_from_config(&config::load_config().notification.delivery);
+    notifier::dispatch(
+        &backends,
+        &NotificationPayload {
+            badge,
+            body: &body,
+            badge_color,
+            icon: &IconType::Agentoast,
+            repo_name: &repo_name,
+            force_focus: false,
+        },
+    );
+
+    Ok(())
+}
+
+fn handle_webhook_hook(secret_name: &str, signature: &str) {
+    let mut raw_body = Vec::new();
+    if let Err(e) = std::io::stdin().read_to_end(&mut raw_body) {
+        let result = HookResult {
+            success: false,
+            error: Some(format!("Failed to read stdin: {}", e)),
+        };
+        println!(
+            "{}",
+            serde_json::to_string(&result).unwrap_or_else(|_| r#"{"success":false}"#.to_string())
+        );
+        return;
+    }
+
+    let result = match run_webhook_hook(secret_name, signature, &raw_body) {
+        Ok(()) => HookResult {
+            success: true,
+            error: None,
+        },
+        Err(e) => HookResult {
+            success: false,
+            error: Some(e),
+        },
     };
 
-    let force_focus = hook_config
-        .focus_events
+    println!(
+        "{}",
+        serde_json::to_string(&result).unwrap_or_else(|_| r#"{"success":false}"#.to_string())
+    );
+}
+
+fn run_generic_hook(name: &str, json_arg: &str) -> Result<(), String> {
+    let hook_config = config::load_config()
+        .notification
+        .agents
+        .generic
+        .get(name)
+        .ok_or_else(|| format!("No generic agent configured with name '{}'", name))?
+        .clone();
+
+    run_generic_hook_with(&hook_config, json_arg)
+}
+
+/// Codex's built-in profile for the generic engine: every event in
+/// `hook_config.events` maps to the same "Stop"/green notification, with the
+/// body (when `include_body` is set) taken from `last-assistant-message`.
+/// Expressing it as a `GenericAgentConfig` lets `run_codex_hook` share the
+/// exact same field-resolution/dedup/dispatch path as config-declared
+/// agents instead of duplicating it.
+fn codex_profile(hook_config: &config::CodexHookConfig) -> config::GenericAgentConfig {
+    let rules = hook_config
+        .events
         .iter()
-        .any(|e| e == &data.event_type);
+        .map(|event| config::GenericRule {
+            field: "type".to_string(),
+            equals: event.clone(),
+            badge: "Stop".to_string(),
+            badge_color: "green".to_string(),
+            icon: "codex".to_string(),
+            body_field: None,
+            focus: hook_config.focus_events.iter().any(|e| e == event),
+        })
+        .collect();
+
+    config::GenericAgentConfig {
+        event_field: "type".to_string(),
+        event_map: HashMap::new(),
+        cwd_field: Some("cwd".to_string()),
+        body_field: hook_config
+            .include_body
+            .then(|| "last-assistant-message".to_string()),
+        focus_events: hook_config.focus_events.clone(),
+        rules,
+        dedup: hook_config.dedup.clone(),
+        channels: hook_config.channels.clone(),
+    }
+}
+
+fn run_codex_hook(json_arg: &str) -> Result<(), String> {
+    let hook_config = config::load_config().notification.agents.codex;
+    run_generic_hook_with(&codex_profile(&hook_config), json_arg)
+}
+
+fn run_generic_hook_with(
+    hook_config: &config::GenericAgentConfig,
+    json_arg: &str,
+) -> Result<(), String> {
+    let config = config::load_config();
+    let data: serde_json::Value =
+        serde_json::from_str(json_arg).map_err(|e| format!("Failed to parse JSON: {}", e))?;
+
+    // Rules are evaluated top-to-bottom and, if present, supersede the older
+    // flat `event_field`/`event_map` lookup kept below for backward compatibility.
+    let matched_rule = hook_config.rules.iter().find(|rule| {
+        resolve_json_path(&data, &rule.field)
+            .and_then(|v| v.as_str())
+            .is_some_and(|v| v == rule.equals)
+    });
+
+    let (badge, badge_color, icon, body_field, force_focus) = if let Some(rule) = matched_rule {
+        let icon: IconType = rule.icon.parse().unwrap_or(IconType::Agentoast);
+        (
+            rule.badge.clone(),
+            rule.badge_color.clone(),
+            icon,
+            rule.body_field.clone().or_else(|| hook_config.body_field.clone()),
+            rule.focus,
+        )
+    } else if !hook_config.rules.is_empty() {
+        // Rules are configured but none matched this event: nothing to notify.
+        return Ok(());
+    } else {
+        let event_key = resolve_json_path(&data, &hook_config.event_field)
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                format!("Field '{}' not found or not a string", hook_config.event_field)
+            })?
+            .to_string();
+
+        let mapping = match hook_config.event_map.get(&event_key) {
+            Some(mapping) => mapping,
+            None => return Ok(()),
+        };
+
+        (
+            mapping.badge.clone(),
+            mapping.badge_color.clone(),
+            IconType::Agentoast,
+            hook_config.body_field.clone(),
+            hook_config.focus_events.iter().any(|e| e == &event_key),
+        )
+    };
+
+    let body = body_field
+        .as_deref()
+        .and_then(|path| resolve_json_path(&data, path))
+        .and_then(|v| v.as_str())
+        .map(truncate_body)
+        .unwrap_or_default();
 
     let mut metadata = HashMap::new();
 
     let repo_name;
-    if let Some(ref dir) = data.directory {
-        let cwd = Path::new(dir);
+    if let Some(cwd_str) = hook_config
+        .cwd_field
+        .as_deref()
+        .and_then(|path| resolve_json_path(&data, path))
+        .and_then(|v| v.as_str())
+    {
+        let cwd = Path::new(cwd_str);
         let git_info = get_git_info(cwd);
         repo_name = git_info.repo_name;
         if !git_info.branch_name.is_empty() {
@@ -422,25 +838,41 @@ fn run_opencode_hook(json_arg: &str) -> Result<(), String> {
     let db_path = config::db_path();
     let conn = db::open_reader(&db_path).map_err(|e| format!("Failed to open database: {}", e))?;
 
-    db::insert_notification(
+    db::insert_notification_deduped(
         &conn,
-        badge,
-        "",
-        badge_color,
-        &IconType::OpenCode,
+        &badge,
+        &body,
+        &badge_color,
+        &icon,
         &metadata,
         &repo_name,
         &tmux_pane,
         &terminal_bundle_id,
         force_focus,
+        hook_config.dedup.enabled.then_some(hook_config.dedup.window_secs),
+        hook_config.dedup.mode,
     )
     .map_err(|e| format!("Failed to insert notification: {}", e))?;
 
+    let backends =
+        notifier::backends_for_channels(&config.notification.delivery, &hook_config.channels);
+    notifier::dispatch(
+        &backends,
+        &NotificationPayload {
+            badge: &badge,
+            body: &body,
+            badge_color: &badge_color,
+            icon: &icon,
+            repo_name: &repo_name,
+            force_focus,
+        },
+    );
+
     Ok(())
 }
 
-fn handle_opencode_hook(json: &str) {
-    let result = match run_opencode_hook(json) {
+fn handle_generic_hook(name: &str, json: &str) {
+    let result = match run_generic_hook(name, json) {
         Ok(()) => HookResult {
             success: true,
             error: None,
@@ -475,6 +907,311 @@ fn handle_claude_hook() {
     );
 }
 
+/// How far a `X-Agentoast-Timestamp` may drift from wall-clock time before a
+/// `Commands::Serve` request is rejected as a possible replay.
+const SERVE_TIMESTAMP_TOLERANCE_SECS: i64 = 300;
+
+/// Verifies a `Commands::Serve` request: HMAC-SHA256(secret, body) must match
+/// `signature` (constant-time), and `timestamp` (unix seconds) must be within
+/// [`SERVE_TIMESTAMP_TOLERANCE_SECS`] of now to reject replayed requests.
+fn verify_serve_request(secret: &str, body: &[u8], signature: &str, timestamp: &str) -> bool {
+    if !verify_webhook_signature(secret, body, signature) {
+        return false;
+    }
+
+    let request_time: i64 = match timestamp.parse() {
+        Ok(t) => t,
+        Err(_) => return false,
+    };
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+
+    (now - request_time).abs() <= SERVE_TIMESTAMP_TOLERANCE_SECS
+}
+
+fn serve_respond(request: tiny_http::Request, status: u16, result: &HookResult) {
+    let body = serde_json::to_string(result).unwrap_or_else(|_| r#"{"success":false}"#.to_string());
+    let header = tiny_http::Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..])
+        .expect("static header is valid");
+    let response = tiny_http::Response::from_string(body)
+        .with_status_code(status)
+        .with_header(header);
+    let _ = request.respond(response);
+}
+
+fn handle_serve_request(mut request: tiny_http::Request, secret: &str) {
+    let route = request.url().to_string();
+
+    let signature = request
+        .headers()
+        .iter()
+        .find(|h| h.field.as_str().as_str().eq_ignore_ascii_case("X-Agentoast-Signature"))
+        .map(|h| h.value.as_str().to_string())
+        .unwrap_or_default();
+    let timestamp = request
+        .headers()
+        .iter()
+        .find(|h| h.field.as_str().as_str().eq_ignore_ascii_case("X-Agentoast-Timestamp"))
+        .map(|h| h.value.as_str().to_string())
+        .unwrap_or_default();
+
+    let mut body = Vec::new();
+    if let Err(e) = request.as_reader().read_to_end(&mut body) {
+        serve_respond(
+            request,
+            400,
+            &HookResult {
+                success: false,
+                error: Some(format!("Failed to read request body: {}", e)),
+            },
+        );
+        return;
+    }
+
+    if !verify_serve_request(secret, &body, &signature, &timestamp) {
+        serve_respond(
+            request,
+            401,
+            &HookResult {
+                success: false,
+                error: Some("Invalid signature or stale timestamp".to_string()),
+            },
+        );
+        return;
+    }
+
+    let payload = String::from_utf8_lossy(&body).to_string();
+    let result = match route.as_str() {
+        "/hook/claude" => run_claude_hook_from_input(&payload),
+        "/hook/codex" => run_codex_hook(&payload),
+        "/hook/opencode" => run_opencode_hook(&payload),
+        _ => Err(format!("Unknown route '{}'", route)),
+    };
+
+    let status = if result.is_ok() { 200 } else { 400 };
+    let hook_result = match result {
+        Ok(()) => HookResult {
+            success: true,
+            error: None,
+        },
+        Err(e) => HookResult {
+            success: false,
+            error: Some(e),
+        },
+    };
+    serve_respond(request, status, &hook_result);
+}
+
+fn run_serve(addr: &str, secret_env: &str) -> Result<(), String> {
+    let secret = std::env::var(secret_env)
+        .map_err(|_| format!("Environment variable '{}' is not set", secret_env))?;
+
+    let server = tiny_http::Server::http(addr)
+        .map_err(|e| format!("Failed to bind to {}: {}", addr, e))?;
+
+    println!(
+        "agentoast serve listening on {} (routes: /hook/claude, /hook/codex, /hook/opencode)",
+        addr
+    );
+
+    for request in server.incoming_requests() {
+        handle_serve_request(request, &secret);
+    }
+
+    Ok(())
+}
+
+/// Matches `path` (query string already stripped) against a route pattern
+/// like `/notifications/{id}`, returning the captured `{...}` segments in
+/// order, or `None` if the pattern doesn't apply.
+fn match_route_pattern(pattern: &str, path: &str) -> Option<Vec<String>> {
+    let pattern_segments: Vec<&str> = pattern.split('/').collect();
+    let path_segments: Vec<&str> = path.split('/').collect();
+    if pattern_segments.len() != path_segments.len() {
+        return None;
+    }
+
+    let mut captures = Vec::new();
+    for (pattern_seg, path_seg) in pattern_segments.iter().zip(path_segments.iter()) {
+        if pattern_seg.starts_with('{') && pattern_seg.ends_with('}') {
+            captures.push((*path_seg).to_string());
+        } else if pattern_seg != path_seg {
+            return None;
+        }
+    }
+    Some(captures)
+}
+
+/// Looks up `key` in a `key=value&key2=value2` query string (already split
+/// off the `?`).
+fn query_param<'a>(query: &'a str, key: &str) -> Option<&'a str> {
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(k, _)| *k == key)
+        .map(|(_, v)| v)
+}
+
+/// Maps `(method, path)` to the first matching `(method, pattern) => handler`
+/// arm, passing the pattern's captured path segments to the handler. Keeps
+/// `handle_admin_request`'s dispatch to a flat, declarative table instead of a
+/// nested match per method.
+macro_rules! admin_routes {
+    ($method:expr, $path:expr, { $($m:literal, $pattern:literal => $handler:expr),+ $(,)? }) => {{
+        let mut matched: Option<(u16, String)> = None;
+        $(
+            if matched.is_none() && $method == $m {
+                if let Some(captures) = match_route_pattern($pattern, $path) {
+                    matched = Some($handler(captures));
+                }
+            }
+        )+
+        matched
+    }};
+}
+
+fn admin_json(status: u16, body: serde_json::Value) -> (u16, String) {
+    (status, body.to_string())
+}
+
+fn admin_list(conn: &db::Connection, query: &str) -> (u16, String) {
+    let limit: i64 = query_param(query, "limit").and_then(|v| v.parse().ok()).unwrap_or(20);
+    match db::get_notifications(conn, limit) {
+        Ok(notifications) => admin_json(200, serde_json::json!(notifications)),
+        Err(e) => admin_json(500, serde_json::json!({ "error": e.to_string() })),
+    }
+}
+
+fn admin_unread_count(conn: &db::Connection) -> (u16, String) {
+    match db::get_unread_count(conn) {
+        Ok(count) => admin_json(200, serde_json::json!({ "unread_count": count })),
+        Err(e) => admin_json(500, serde_json::json!({ "error": e.to_string() })),
+    }
+}
+
+fn admin_after_id(conn: &db::Connection, id: &str) -> (u16, String) {
+    let id: i64 = match id.parse() {
+        Ok(id) => id,
+        Err(_) => return admin_json(400, serde_json::json!({ "error": format!("invalid id '{}'", id) })),
+    };
+    match db::get_notifications_after_id(conn, id) {
+        Ok(notifications) => admin_json(200, serde_json::json!(notifications)),
+        Err(e) => admin_json(500, serde_json::json!({ "error": e.to_string() })),
+    }
+}
+
+fn admin_delete_one(conn: &db::Connection, id: &str) -> (u16, String) {
+    let parsed_id: i64 = match id.parse() {
+        Ok(id) => id,
+        Err(_) => return admin_json(400, serde_json::json!({ "error": format!("invalid id '{}'", id) })),
+    };
+    match db::delete_notification(conn, parsed_id) {
+        Ok(()) => admin_json(200, serde_json::json!({ "status": "ok" })),
+        Err(e) => admin_json(500, serde_json::json!({ "error": e.to_string() })),
+    }
+}
+
+fn admin_delete_by_query(conn: &db::Connection, query: &str) -> (u16, String) {
+    let result = match query_param(query, "pane") {
+        Some(pane) => db::delete_notifications_by_pane(conn, pane).map(|_| ()),
+        None => db::delete_all_notifications(conn),
+    };
+    match result {
+        Ok(()) => admin_json(200, serde_json::json!({ "status": "ok" })),
+        Err(e) => admin_json(500, serde_json::json!({ "error": e.to_string() })),
+    }
+}
+
+fn admin_respond(request: tiny_http::Request, status: u16, body: String) {
+    let header = tiny_http::Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..])
+        .expect("static header is valid");
+    let response = tiny_http::Response::from_string(body)
+        .with_status_code(status)
+        .with_header(header);
+    let _ = request.respond(response);
+}
+
+fn handle_admin_request(request: tiny_http::Request, db_path: &Path, token: Option<&str>) {
+    if let Some(expected) = token {
+        let provided = request
+            .headers()
+            .iter()
+            .find(|h| h.field.as_str().as_str().eq_ignore_ascii_case("Authorization"))
+            .map(|h| h.value.as_str().to_string())
+            .unwrap_or_default();
+        let provided = provided.strip_prefix("Bearer ").unwrap_or(&provided);
+        if !constant_time_eq(provided.as_bytes(), expected.as_bytes()) {
+            admin_respond(
+                request,
+                401,
+                serde_json::json!({ "error": "unauthorized" }).to_string(),
+            );
+            return;
+        }
+    }
+
+    let method = request.method().to_string();
+    let url = request.url().to_string();
+    let (path, query) = match url.split_once('?') {
+        Some((path, query)) => (path.to_string(), query.to_string()),
+        None => (url.clone(), String::new()),
+    };
+
+    let conn = match db::open_reader(db_path) {
+        Ok(conn) => conn,
+        Err(e) => {
+            admin_respond(
+                request,
+                500,
+                serde_json::json!({ "error": format!("Failed to open database: {}", e) }).to_string(),
+            );
+            return;
+        }
+    };
+
+    let response = admin_routes!(method.as_str(), path.as_str(), {
+        "GET", "/notifications" => |_: Vec<String>| admin_list(&conn, &query),
+        "GET", "/notifications/unread_count" => |_: Vec<String>| admin_unread_count(&conn),
+        "GET", "/notifications/after/{id}" => |caps: Vec<String>| admin_after_id(&conn, &caps[0]),
+        "DELETE", "/notifications/{id}" => |caps: Vec<String>| admin_delete_one(&conn, &caps[0]),
+        "DELETE", "/notifications" => |_: Vec<String>| admin_delete_by_query(&conn, &query),
+    });
+
+    match response {
+        Some((status, body)) => admin_respond(request, status, body),
+        None => admin_respond(
+            request,
+            404,
+            serde_json::json!({ "error": format!("no route for {} {}", method, path) }).to_string(),
+        ),
+    }
+}
+
+fn run_admin_server() -> Result<(), String> {
+    let cfg = config::load_config().admin;
+    if !cfg.enabled {
+        return Err("Admin API is disabled (set enabled = true under [admin] in config)".to_string());
+    }
+
+    let addr = format!("127.0.0.1:{}", cfg.port);
+    let server =
+        tiny_http::Server::http(&addr).map_err(|e| format!("Failed to bind to {}: {}", addr, e))?;
+
+    println!(
+        "agentoast admin API listening on {} (routes: GET/DELETE /notifications, GET /notifications/unread_count, GET /notifications/after/{{id}}, DELETE /notifications/{{id}})",
+        addr
+    );
+
+    let db_path = config::db_path();
+    for request in server.incoming_requests() {
+        handle_admin_request(request, &db_path, cfg.token.as_deref());
+    }
+
+    Ok(())
+}
+
 fn main() {
     let cli = Cli::parse();
 
@@ -489,6 +1226,7 @@ fn main() {
             bundle_id,
             focus,
             meta,
+            no_deliver,
         } => {
             let icon_type: IconType = icon.parse().unwrap_or_else(|e: String| {
                 eprintln!(
@@ -551,7 +1289,24 @@ fn main() {
                 &terminal_bundle_id,
                 focus,
             ) {
-                Ok(id) => println!("Notification saved (id={})", id),
+                Ok(id) => {
+                    println!("Notification saved (id={})", id);
+                    if !no_deliver {
+                        let backends =
+                            notifier::backends_from_config(&config::load_config().notification.delivery);
+                        notifier::dispatch(
+                            &backends,
+                            &NotificationPayload {
+                                badge: &badge,
+                                body: &body,
+                                badge_color: &badge_color,
+                                icon: &icon_type,
+                                repo_name: &repo,
+                                force_focus: focus,
+                            },
+                        );
+                    }
+                }
                 Err(e) => {
                     eprintln!("Failed to insert notification: {}", e);
                     std::process::exit(1);
@@ -562,6 +1317,11 @@ fn main() {
             HookAgent::Claude => handle_claude_hook(),
             HookAgent::Codex { json } => handle_codex_hook(&json),
             HookAgent::Opencode { json } => handle_opencode_hook(&json),
+            HookAgent::Webhook {
+                secret_name,
+                signature,
+            } => handle_webhook_hook(&secret_name, &signature),
+            HookAgent::Generic { name, json } => handle_generic_hook(&name, &json),
         },
         Commands::Config => {
             let config_path = config::ensure_config_file().unwrap_or_else(|e| {
@@ -584,6 +1344,53 @@ fn main() {
                 std::process::exit(status.code().unwrap_or(1));
             }
         }
+        Commands::Serve { addr, secret_env } => {
+            if let Err(e) = run_serve(&addr, &secret_env) {
+                eprintln!("Failed to start server: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Commands::Admin => {
+            if let Err(e) = run_admin_server() {
+                eprintln!("Failed to start admin API: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Commands::Watch { interval_ms } => {
+            if let Err(e) = tui::run_watch(interval_ms) {
+                eprintln!("Watch failed: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Commands::Repl => {
+            if let Err(e) = repl::run_repl() {
+                eprintln!("Repl failed: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Commands::Snooze { id, r#for } => {
+            let interval_str =
+                r#for.unwrap_or_else(|| config::load_config().notification.snooze.default_interval);
+            let duration = agentoast_shared::interval::parse_interval(&interval_str)
+                .unwrap_or_else(|e| {
+                    eprintln!("Invalid --for interval '{}': {}", interval_str, e);
+                    std::process::exit(1);
+                });
+
+            let db_path = config::db_path();
+            let conn = db::open_reader(&db_path).unwrap_or_else(|e| {
+                eprintln!("Failed to open database: {}", e);
+                std::process::exit(1);
+            });
+
+            match db::snooze_notification(&conn, id, duration) {
+                Ok(()) => println!("Notification {} snoozed for {}", id, interval_str),
+                Err(e) => {
+                    eprintln!("Failed to snooze notification {}: {}", id, e);
+                    std::process::exit(1);
+                }
+            }
+        }
         Commands::List { limit } => {
             let db_path = config::db_path();
             let conn = db::open_reader(&db_path).unwrap_or_else(|e| {
@@ -614,9 +1421,21 @@ fn main() {
                         } else {
                             format!(" (pane:{})", n.tmux_pane)
                         };
+                        let coalesce_str = if n.coalesce_count > 1 {
+                            format!(" x{}", n.coalesce_count)
+                        } else {
+                            String::new()
+                        };
                         println!(
-                            "{} [{}] {} [{}]{} {}{}",
-                            read_mark, n.id, n.badge, n.icon, pane_str, n.body, meta_str
+                            "{} [{}] {}{} [{}]{} {}{}",
+                            read_mark,
+                            n.id,
+                            n.badge,
+                            coalesce_str,
+                            n.icon,
+                            pane_str,
+                            n.body,
+                            meta_str
                         );
                     }
                 }
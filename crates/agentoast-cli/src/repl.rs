@@ -0,0 +1,428 @@
+//! Interactive control REPL (`agentoast repl`) for inspecting and overriding
+//! the status of tmux panes the notification hooks would otherwise classify
+//! on their own. Modeled on small Rust-shell command loops (moros-style
+//! history + tab completion via `rustyline`) with a typed dispatch table
+//! (`{name, aliases, handler}`) so new commands are easy to register,
+//! mirroring `HookAgent`'s typed-subcommand shape in `main.rs`.
+
+use std::collections::HashMap;
+use std::process::Command;
+
+use agentoast_shared::config::{self, CustomAgentConfig};
+use agentoast_shared::db;
+use agentoast_shared::detect;
+use agentoast_shared::models::AgentStatus;
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::Validator;
+use rustyline::{Context, Editor, Helper};
+
+struct ReplState {
+    custom_agents: HashMap<String, CustomAgentConfig>,
+    db_conn: Option<db::Connection>,
+    /// Pane-id -> forced status, consulted by `list`/`status` before real
+    /// detection. Session-local only; not persisted.
+    overrides: HashMap<String, AgentStatus>,
+}
+
+struct ReplCommand {
+    name: &'static str,
+    aliases: &'static [&'static str],
+    help: &'static str,
+    handler: fn(&mut ReplState, &[&str]),
+}
+
+const COMMANDS: &[ReplCommand] = &[
+    ReplCommand {
+        name: "list",
+        aliases: &["ls"],
+        help: "list [ls]                 List detected agent panes with their current status",
+        handler: cmd_list,
+    },
+    ReplCommand {
+        name: "status",
+        aliases: &[],
+        help: "status <pane_id>          Re-run detection for one pane",
+        handler: cmd_status,
+    },
+    ReplCommand {
+        name: "override",
+        aliases: &["set"],
+        help: "override <pane_id> <s>    Force a pane's status (running/waiting/idle)",
+        handler: cmd_override,
+    },
+    ReplCommand {
+        name: "clear",
+        aliases: &[],
+        help: "clear <pane_id>           Remove a forced status",
+        handler: cmd_clear,
+    },
+    ReplCommand {
+        name: "dump",
+        aliases: &[],
+        help: "dump <pane_id>            Print the raw captured tail used for detection",
+        handler: cmd_dump,
+    },
+    ReplCommand {
+        name: "help",
+        aliases: &["?"],
+        help: "help [?]                  Show this command list",
+        handler: cmd_help,
+    },
+];
+
+/// Runs the REPL until the user quits (`quit`/`exit`, Ctrl-D, or Ctrl-C).
+pub fn run_repl() -> Result<(), String> {
+    let mut custom_agents = config::default_custom_agents();
+    custom_agents.extend(config::load_config().agent_detection.custom);
+
+    let mut state = ReplState {
+        custom_agents,
+        db_conn: db::open_reader(&config::db_path()).ok(),
+        overrides: HashMap::new(),
+    };
+
+    let history_path = config::data_dir().join("repl_history.txt");
+    let mut editor: Editor<ReplHelper, rustyline::history::DefaultHistory> =
+        Editor::new().map_err(|e| format!("failed to start REPL: {}", e))?;
+    let _ = editor.load_history(&history_path);
+
+    println!("agentoast control REPL. Type 'help' for commands, 'quit' to exit.");
+
+    loop {
+        editor.set_helper(Some(ReplHelper {
+            pane_ids: list_pane_ids(),
+            agent_types: known_agent_types(&state.custom_agents),
+        }));
+
+        match editor.readline("agentoast> ") {
+            Ok(line) => {
+                let line = line.trim();
+                if line.is_empty() {
+                    continue;
+                }
+                let _ = editor.add_history_entry(line);
+
+                let mut parts = line.split_whitespace();
+                let name = parts.next().unwrap_or("");
+                let args: Vec<&str> = parts.collect();
+
+                if name == "quit" || name == "exit" {
+                    break;
+                }
+                match COMMANDS
+                    .iter()
+                    .find(|c| c.name == name || c.aliases.contains(&name))
+                {
+                    Some(cmd) => (cmd.handler)(&mut state, &args),
+                    None => println!("Unknown command '{}'. Type 'help' for a list.", name),
+                }
+            }
+            Err(ReadlineError::Interrupted) | Err(ReadlineError::Eof) => break,
+            Err(e) => {
+                eprintln!("readline error: {}", e);
+                break;
+            }
+        }
+    }
+
+    let _ = editor.save_history(&history_path);
+    Ok(())
+}
+
+fn cmd_help(_state: &mut ReplState, _args: &[&str]) {
+    for cmd in COMMANDS {
+        println!("  {}", cmd.help);
+    }
+    println!("  quit | exit               Leave the REPL");
+}
+
+fn cmd_list(state: &mut ReplState, _args: &[&str]) {
+    let panes = detected_panes(&state.custom_agents);
+    if panes.is_empty() {
+        println!("No agent panes detected.");
+        return;
+    }
+    for pane in &panes {
+        let status = state
+            .overrides
+            .get(&pane.pane_id)
+            .map(|s| format!("{} (overridden)", status_str(*s)))
+            .unwrap_or_else(|| status_str(pane_status(state, pane)).to_string());
+        println!("{}  {:<12}  {}", pane.pane_id, pane.agent_type, status);
+    }
+}
+
+fn cmd_status(state: &mut ReplState, args: &[&str]) {
+    let Some(&pane_id) = args.first() else {
+        println!("usage: status <pane_id>");
+        return;
+    };
+    let Some(pane) = detected_panes(&state.custom_agents)
+        .into_iter()
+        .find(|p| p.pane_id == pane_id)
+    else {
+        println!("pane {} not found (or has no detected agent)", pane_id);
+        return;
+    };
+    let status = state
+        .overrides
+        .get(pane_id)
+        .map(|s| format!("{} (overridden)", status_str(*s)))
+        .unwrap_or_else(|| status_str(pane_status(state, &pane)).to_string());
+    println!("{}  {}  {}", pane.pane_id, pane.agent_type, status);
+}
+
+fn cmd_override(state: &mut ReplState, args: &[&str]) {
+    let (Some(&pane_id), Some(&status_arg)) = (args.first(), args.get(1)) else {
+        println!("usage: override <pane_id> <running|waiting|idle>");
+        return;
+    };
+    match parse_status(status_arg) {
+        Some(status) => {
+            state.overrides.insert(pane_id.to_string(), status);
+            println!("{} forced to {}", pane_id, status_str(status));
+        }
+        None => println!("unknown status '{}' (want running|waiting|idle)", status_arg),
+    }
+}
+
+fn cmd_clear(state: &mut ReplState, args: &[&str]) {
+    let Some(&pane_id) = args.first() else {
+        println!("usage: clear <pane_id>");
+        return;
+    };
+    if state.overrides.remove(pane_id).is_some() {
+        println!("override cleared for {}", pane_id);
+    } else {
+        println!("{} has no override", pane_id);
+    }
+}
+
+fn cmd_dump(_state: &mut ReplState, args: &[&str]) {
+    let Some(&pane_id) = args.first() else {
+        println!("usage: dump <pane_id>");
+        return;
+    };
+    match capture_pane(pane_id) {
+        Some(content) => {
+            for line in content.lines().rev().filter(|l| !l.trim().is_empty()).take(30).collect::<Vec<_>>().into_iter().rev() {
+                println!("{}", line);
+            }
+        }
+        None => println!("failed to capture pane {} (is tmux running?)", pane_id),
+    }
+}
+
+/// A pane with a detected (config-driven or built-in) agent type.
+struct DetectedPane {
+    pane_id: String,
+    agent_type: String,
+}
+
+fn pane_status(state: &ReplState, pane: &DetectedPane) -> AgentStatus {
+    let Some(content) = capture_pane(&pane.pane_id) else {
+        return AgentStatus::Running;
+    };
+    match state.custom_agents.get(&pane.agent_type) {
+        Some(cfg) => {
+            // The REPL's own `capture_pane` doesn't pass tmux `-e`, so these
+            // lines always parse with empty SGR spans — the plain-text
+            // fallback path, same as a fixture with no escape sequences.
+            let lines = agentoast_shared::ansi::parse_lines(&content);
+            detect::classify_custom_status(&state.db_conn, &pane.pane_id, &lines, cfg).0
+        }
+        // Every built-in agent type ships a `default_custom_agents` entry, so
+        // this only hits for a `[agent_detection.custom.*]` entry removed
+        // after a pane was detected; the REPL can still `dump` its content.
+        None => AgentStatus::Running,
+    }
+}
+
+fn status_str(status: AgentStatus) -> &'static str {
+    match status {
+        AgentStatus::Running => "running",
+        AgentStatus::Waiting => "waiting",
+        AgentStatus::Idle => "idle",
+    }
+}
+
+fn parse_status(s: &str) -> Option<AgentStatus> {
+    match s.to_ascii_lowercase().as_str() {
+        "running" | "run" => Some(AgentStatus::Running),
+        "waiting" | "wait" => Some(AgentStatus::Waiting),
+        "idle" => Some(AgentStatus::Idle),
+        _ => None,
+    }
+}
+
+fn known_agent_types(custom_agents: &HashMap<String, CustomAgentConfig>) -> Vec<String> {
+    let mut types: Vec<String> = custom_agents.keys().cloned().collect();
+    types.sort();
+    types
+}
+
+fn list_pane_ids() -> Vec<String> {
+    let output = Command::new("tmux")
+        .args(["list-panes", "-a", "-F", "#{pane_id}"])
+        .output();
+    match output {
+        Ok(o) if o.status.success() => String::from_utf8_lossy(&o.stdout)
+            .lines()
+            .map(|l| l.to_string())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Scans every tmux pane's descendant process tree for a known agent
+/// process name, the same walk `sessions::detect_agent` does in the GUI.
+fn detected_panes(custom_agents: &HashMap<String, CustomAgentConfig>) -> Vec<DetectedPane> {
+    let output = Command::new("tmux")
+        .args(["list-panes", "-a", "-F", "#{pane_id}|||#{pane_pid}"])
+        .output();
+    let Ok(output) = output else {
+        return Vec::new();
+    };
+    if !output.status.success() {
+        return Vec::new();
+    }
+
+    let tree = build_process_tree();
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            let (pane_id, pane_pid) = line.split_once("|||")?;
+            let pane_pid: u32 = pane_pid.parse().ok()?;
+            let agent_type = detect_agent_type(&tree, pane_pid, custom_agents)?;
+            Some(DetectedPane {
+                pane_id: pane_id.to_string(),
+                agent_type,
+            })
+        })
+        .collect()
+}
+
+struct ProcessTree {
+    children: HashMap<u32, Vec<u32>>,
+    commands: HashMap<u32, String>,
+}
+
+fn build_process_tree() -> ProcessTree {
+    let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+    let mut commands: HashMap<u32, String> = HashMap::new();
+
+    let Ok(output) = Command::new("ps").args(["-eo", "pid,ppid,comm"]).output() else {
+        return ProcessTree { children, commands };
+    };
+
+    for line in String::from_utf8_lossy(&output.stdout).lines().skip(1) {
+        let mut iter = line.split_whitespace();
+        let (Some(pid), Some(ppid)) = (
+            iter.next().and_then(|s| s.parse().ok()),
+            iter.next().and_then(|s| s.parse::<u32>().ok()),
+        ) else {
+            continue;
+        };
+        let comm: String = iter.collect::<Vec<&str>>().join(" ");
+        if comm.is_empty() {
+            continue;
+        }
+        children.entry(ppid).or_default().push(pid);
+        commands.insert(pid, comm);
+    }
+
+    ProcessTree { children, commands }
+}
+
+fn detect_agent_type(
+    tree: &ProcessTree,
+    pane_pid: u32,
+    custom_agents: &HashMap<String, CustomAgentConfig>,
+) -> Option<String> {
+    let mut stack = vec![pane_pid];
+    let mut visited = std::collections::HashSet::new();
+    while let Some(current) = stack.pop() {
+        if !visited.insert(current) {
+            continue;
+        }
+        if let Some(child_pids) = tree.children.get(&current) {
+            for &child in child_pids {
+                if let Some(comm) = tree.commands.get(&child) {
+                    let basename = comm.rsplit('/').next().unwrap_or(comm);
+                    for (agent_type, cfg) in custom_agents {
+                        if cfg.process_names.iter().any(|p| p == basename) {
+                            return Some(agent_type.clone());
+                        }
+                    }
+                }
+                stack.push(child);
+            }
+        }
+    }
+    None
+}
+
+fn capture_pane(pane_id: &str) -> Option<String> {
+    let output = Command::new("tmux")
+        .args(["capture-pane", "-t", pane_id, "-p"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Tab-completes command names (first word) and pane ids / agent type names
+/// (remaining words), refreshed before every prompt in `run_repl`.
+struct ReplHelper {
+    pane_ids: Vec<String>,
+    agent_types: Vec<String>,
+}
+
+impl Completer for ReplHelper {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        let start = line[..pos].rfind(' ').map(|i| i + 1).unwrap_or(0);
+        let word = &line[start..pos];
+        let is_first_word = !line[..start].contains(|c: char| !c.is_whitespace());
+
+        let candidates: Vec<String> = if is_first_word {
+            COMMANDS
+                .iter()
+                .flat_map(|c| std::iter::once(c.name).chain(c.aliases.iter().copied()))
+                .chain(["quit", "exit"])
+                .filter(|c| c.starts_with(word))
+                .map(|s| s.to_string())
+                .collect()
+        } else {
+            self.pane_ids
+                .iter()
+                .chain(self.agent_types.iter())
+                .filter(|c| c.starts_with(word))
+                .cloned()
+                .collect()
+        };
+
+        Ok((start, candidates))
+    }
+}
+
+impl Hinter for ReplHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ReplHelper {}
+
+impl Validator for ReplHelper {}
+
+impl Helper for ReplHelper {}
@@ -0,0 +1,271 @@
+use std::io;
+use std::time::{Duration, Instant};
+
+use agentoast_shared::config;
+use agentoast_shared::db;
+use agentoast_shared::models::Notification;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::execute;
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph};
+use ratatui::Terminal;
+
+/// In-memory state for the `agentoast watch` inbox, refreshed from the DB on
+/// an interval and re-rendered from scratch each tick (ratatui diffs the
+/// terminal itself, so there's no need to track dirty regions here).
+struct WatchState {
+    notifications: Vec<Notification>,
+    selected: usize,
+    repo_filter: Option<String>,
+    icon_filter: Option<String>,
+    status: String,
+}
+
+impl WatchState {
+    fn new() -> Self {
+        WatchState {
+            notifications: Vec::new(),
+            selected: 0,
+            repo_filter: None,
+            icon_filter: None,
+            status: String::new(),
+        }
+    }
+
+    fn refresh(&mut self, conn: &db::Connection) {
+        match db::get_notifications(conn, 500) {
+            Ok(mut notifications) => {
+                notifications.sort_by(|a, b| a.repo.cmp(&b.repo).then(b.created_at.cmp(&a.created_at)));
+                self.notifications = notifications;
+                if self.selected >= self.visible().len().max(1) {
+                    self.selected = self.visible().len().saturating_sub(1);
+                }
+            }
+            Err(e) => self.status = format!("Failed to refresh: {}", e),
+        }
+    }
+
+    /// Indices into `notifications` that pass the current repo/icon filters.
+    fn visible(&self) -> Vec<usize> {
+        self.notifications
+            .iter()
+            .enumerate()
+            .filter(|(_, n)| {
+                self.repo_filter.as_deref().map_or(true, |r| n.repo == r)
+                    && self.icon_filter.as_deref().map_or(true, |i| n.icon == i)
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    fn selected_notification(&self) -> Option<&Notification> {
+        self.visible()
+            .get(self.selected)
+            .and_then(|&i| self.notifications.get(i))
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        let len = self.visible().len();
+        if len == 0 {
+            self.selected = 0;
+            return;
+        }
+        let current = self.selected as isize;
+        self.selected = (current + delta).clamp(0, len as isize - 1) as usize;
+    }
+
+    /// Cycles the repo filter through: off -> each distinct repo (in
+    /// notification order) -> off.
+    fn cycle_repo_filter(&mut self) {
+        let mut repos: Vec<&str> = Vec::new();
+        for n in &self.notifications {
+            if !repos.contains(&n.repo.as_str()) {
+                repos.push(&n.repo);
+            }
+        }
+        self.repo_filter = match &self.repo_filter {
+            None => repos.first().map(|r| r.to_string()),
+            Some(current) => {
+                let idx = repos.iter().position(|r| *r == current);
+                match idx {
+                    Some(i) if i + 1 < repos.len() => Some(repos[i + 1].to_string()),
+                    _ => None,
+                }
+            }
+        };
+        self.selected = 0;
+    }
+
+    fn cycle_icon_filter(&mut self) {
+        let mut icons: Vec<&str> = Vec::new();
+        for n in &self.notifications {
+            if !icons.contains(&n.icon.as_str()) {
+                icons.push(&n.icon);
+            }
+        }
+        self.icon_filter = match &self.icon_filter {
+            None => icons.first().map(|i| i.to_string()),
+            Some(current) => {
+                let idx = icons.iter().position(|i| *i == current);
+                match idx {
+                    Some(i) if i + 1 < icons.len() => Some(icons[i + 1].to_string()),
+                    _ => None,
+                }
+            }
+        };
+        self.selected = 0;
+    }
+}
+
+fn focus_notification(n: &Notification) {
+    if n.tmux_pane.is_empty() {
+        return;
+    }
+    let _ = std::process::Command::new("tmux")
+        .args(["switch-client", "-t", &n.tmux_pane])
+        .output();
+    let _ = std::process::Command::new("tmux")
+        .args(["select-window", "-t", &n.tmux_pane])
+        .output();
+    let _ = std::process::Command::new("tmux")
+        .args(["select-pane", "-t", &n.tmux_pane])
+        .output();
+}
+
+fn render(frame: &mut ratatui::Frame, state: &WatchState) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(frame.area());
+
+    let visible = state.visible();
+    let items: Vec<ListItem> = visible
+        .iter()
+        .map(|&i| {
+            let n = &state.notifications[i];
+            let read_mark = if n.is_read { " " } else { "*" };
+            let coalesce = if n.coalesce_count > 1 {
+                format!(" x{}", n.coalesce_count)
+            } else {
+                String::new()
+            };
+            let style = if n.is_read {
+                Style::default().fg(Color::DarkGray)
+            } else {
+                Style::default().add_modifier(Modifier::BOLD)
+            };
+            ListItem::new(Line::from(vec![Span::raw(format!(
+                "{} [{}] {}{} {}",
+                read_mark, n.repo, n.badge, coalesce, n.body
+            ))]))
+            .style(style)
+        })
+        .collect();
+
+    let title = format!(
+        "Agentoast Watch{}{}",
+        state
+            .repo_filter
+            .as_ref()
+            .map(|r| format!(" | repo:{}", r))
+            .unwrap_or_default(),
+        state
+            .icon_filter
+            .as_ref()
+            .map(|i| format!(" | icon:{}", i))
+            .unwrap_or_default()
+    );
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(Style::default().bg(Color::Blue).add_modifier(Modifier::BOLD));
+
+    let mut list_state = ListState::default();
+    if !visible.is_empty() {
+        list_state.select(Some(state.selected));
+    }
+    frame.render_stateful_widget(list, chunks[0], &mut list_state);
+
+    let help = if state.status.is_empty() {
+        "j/k move  r read  u unread  f focus  c repo filter  i icon filter  q quit".to_string()
+    } else {
+        state.status.clone()
+    };
+    frame.render_widget(Paragraph::new(help), chunks[1]);
+}
+
+/// Opens an interactive inbox TUI: polls the DB every `interval_ms`,
+/// re-rendering whenever new notifications arrive or the user acts on one.
+pub fn run_watch(interval_ms: u64) -> Result<(), String> {
+    let db_path = config::db_path();
+    let conn =
+        db::open_reader(&db_path).map_err(|e| format!("Failed to open database: {}", e))?;
+
+    enable_raw_mode().map_err(|e| format!("Failed to enable raw mode: {}", e))?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen).map_err(|e| e.to_string())?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend).map_err(|e| e.to_string())?;
+
+    let mut state = WatchState::new();
+    state.refresh(&conn);
+    let mut last_poll = Instant::now();
+    let poll_interval = Duration::from_millis(interval_ms.max(100));
+
+    let result = (|| -> Result<(), String> {
+        loop {
+            terminal
+                .draw(|frame| render(frame, &state))
+                .map_err(|e| e.to_string())?;
+
+            let timeout = poll_interval.saturating_sub(last_poll.elapsed());
+            if event::poll(timeout).map_err(|e| e.to_string())? {
+                if let Event::Key(key) = event::read().map_err(|e| e.to_string())? {
+                    if key.kind == KeyEventKind::Press {
+                        match key.code {
+                            KeyCode::Char('q') | KeyCode::Esc => break,
+                            KeyCode::Char('j') | KeyCode::Down => state.move_selection(1),
+                            KeyCode::Char('k') | KeyCode::Up => state.move_selection(-1),
+                            KeyCode::Char('r') => {
+                                if let Some(n) = state.selected_notification() {
+                                    let _ = db::mark_read(&conn, n.id);
+                                    state.refresh(&conn);
+                                }
+                            }
+                            KeyCode::Char('u') => {
+                                if let Some(n) = state.selected_notification() {
+                                    let _ = db::mark_unread(&conn, n.id);
+                                    state.refresh(&conn);
+                                }
+                            }
+                            KeyCode::Char('f') => {
+                                if let Some(n) = state.selected_notification() {
+                                    focus_notification(n);
+                                }
+                            }
+                            KeyCode::Char('c') => state.cycle_repo_filter(),
+                            KeyCode::Char('i') => state.cycle_icon_filter(),
+                            _ => {}
+                        }
+                    }
+                }
+            }
+
+            if last_poll.elapsed() >= poll_interval {
+                state.refresh(&conn);
+                last_poll = Instant::now();
+            }
+        }
+        Ok(())
+    })();
+
+    disable_raw_mode().ok();
+    execute!(terminal.backend_mut(), LeaveAlternateScreen).ok();
+
+    result
+}
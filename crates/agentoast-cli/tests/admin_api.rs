@@ -0,0 +1,143 @@
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::process::{Child, Command, Stdio};
+use std::time::Duration;
+
+use agentoast_shared::db;
+
+struct AdminServer {
+    child: Child,
+    port: u16,
+}
+
+impl Drop for AdminServer {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+fn spawn_admin(port: u16, data_dir: &std::path::Path, config_dir: &std::path::Path) -> AdminServer {
+    write_config(
+        config_dir,
+        &format!(
+            r#"
+[admin]
+enabled = true
+port = {port}
+"#
+        ),
+    );
+
+    let child = Command::new(env!("CARGO_BIN_EXE_agentoast"))
+        .args(["admin"])
+        .env("XDG_DATA_HOME", data_dir)
+        .env("XDG_CONFIG_HOME", config_dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to spawn agentoast admin");
+
+    // Give the listener a moment to bind before the first request.
+    std::thread::sleep(Duration::from_millis(200));
+
+    AdminServer { child, port }
+}
+
+fn setup_db(data_dir: &std::path::Path) -> std::path::PathBuf {
+    let db_path = data_dir.join("agentoast").join("notifications.db");
+    std::fs::create_dir_all(db_path.parent().unwrap()).unwrap();
+    let _conn = db::open(&db_path).unwrap();
+    db_path
+}
+
+fn write_config(config_dir: &std::path::Path, content: &str) {
+    let config_path = config_dir.join("agentoast").join("config.toml");
+    std::fs::create_dir_all(config_path.parent().unwrap()).unwrap();
+    std::fs::write(config_path, content).unwrap();
+}
+
+/// Sends a bare-bones HTTP/1.1 request over a fresh connection and returns
+/// `(status, body)`. Good enough for asserting on this test's own server
+/// without pulling in an HTTP client dependency just for tests.
+fn request(port: u16, method: &str, path: &str) -> (u16, String) {
+    let mut stream = TcpStream::connect(("127.0.0.1", port)).expect("Failed to connect");
+    stream
+        .write_all(
+            format!("{method} {path} HTTP/1.1\r\nHost: 127.0.0.1\r\nConnection: close\r\n\r\n")
+                .as_bytes(),
+        )
+        .unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    let (head, body) = response.split_once("\r\n\r\n").expect("malformed response");
+    let status: u16 = head
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|code| code.parse().ok())
+        .expect("malformed status line");
+
+    (status, body.to_string())
+}
+
+#[test]
+fn lists_and_deletes_a_notification() {
+    let data_dir = tempfile::tempdir().unwrap();
+    let config_dir = tempfile::tempdir().unwrap();
+    let db_path = setup_db(data_dir.path());
+
+    {
+        let conn = db::open_reader(&db_path).unwrap();
+        db::insert_notification(
+            &conn,
+            "Stop",
+            "done",
+            "green",
+            &agentoast_shared::models::IconType::Codex,
+            &std::collections::HashMap::new(),
+            "agentoast",
+            "",
+            "",
+            false,
+        )
+        .unwrap();
+    }
+
+    let server = spawn_admin(14318, data_dir.path(), config_dir.path());
+
+    let (status, body) = request(server.port, "GET", "/notifications/unread_count");
+    assert_eq!(status, 200);
+    let parsed: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(parsed["unread_count"], 1);
+
+    let (status, body) = request(server.port, "GET", "/notifications");
+    assert_eq!(status, 200);
+    let notifications: Vec<agentoast_shared::models::Notification> =
+        serde_json::from_str(&body).unwrap();
+    assert_eq!(notifications.len(), 1);
+    let id = notifications[0].id;
+
+    let (status, _) = request(server.port, "DELETE", &format!("/notifications/{id}"));
+    assert_eq!(status, 200);
+
+    let (status, body) = request(server.port, "GET", "/notifications/unread_count");
+    assert_eq!(status, 200);
+    let parsed: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(parsed["unread_count"], 0);
+}
+
+#[test]
+fn rejects_unknown_route() {
+    let data_dir = tempfile::tempdir().unwrap();
+    let config_dir = tempfile::tempdir().unwrap();
+    setup_db(data_dir.path());
+
+    let server = spawn_admin(14319, data_dir.path(), config_dir.path());
+
+    let (status, _) = request(server.port, "GET", "/not-a-route");
+    assert_eq!(status, 404);
+}
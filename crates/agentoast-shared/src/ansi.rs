@@ -0,0 +1,102 @@
+//! ANSI/SGR-aware parsing of tmux pane captures. Agents that reflow or
+//! recolor their status line break plain-text substring matching, so
+//! `crate::capture`'s `TmuxCapture` backend always captures with `-e`
+//! (preserving SGR escape sequences); `parse_lines` here splits each
+//! resulting line into `(text, active-SGR-codes)` spans, giving
+//! `crate::detect` both a plain-text view (for substring/regex checks) and a
+//! styled view (for color-gated rules like a highlighted selection cursor or
+//! an accent-colored spinner) from a single capture. Content with no escape
+//! sequences (a fixture, or a pane captured without `-e`) parses into spans
+//! that simply carry no SGR codes, so callers get a plain-text fallback for
+//! free rather than needing a separate code path.
+
+/// A run of text that shares the same active SGR codes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StyledSpan {
+    pub text: String,
+    /// Active SGR parameter codes (e.g. `"1"`, `"32"`), empty when the span
+    /// carries no styling.
+    pub sgr: Vec<String>,
+}
+
+/// A single captured pane line, as both a plain-text view and a styled view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaneLine {
+    pub plain: String,
+    pub spans: Vec<StyledSpan>,
+}
+
+impl PaneLine {
+    /// Whether any span on this line carries `code` among its active SGR
+    /// parameters — the building block behind color-gated detection rules.
+    pub fn has_sgr(&self, code: &str) -> bool {
+        self.spans.iter().any(|span| span.sgr.iter().any(|c| c == code))
+    }
+}
+
+/// Parses raw (possibly escape-laden) pane content into per-line spans.
+pub fn parse_lines(content: &str) -> Vec<PaneLine> {
+    content.lines().map(parse_line).collect()
+}
+
+fn parse_line(line: &str) -> PaneLine {
+    // Trim trailing tabs/spaces (tmux pads lines to the pane width) and
+    // append a reset so a span still "open" at end-of-line never bleeds its
+    // SGR state into the next line we parse.
+    let trimmed = line.trim_end_matches([' ', '\t']);
+    let normalized = format!("{trimmed}\u{1b}[0m");
+
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    let mut active: Vec<String> = Vec::new();
+    let mut current_text = String::new();
+
+    let mut chars = normalized.char_indices().peekable();
+    while let Some((i, ch)) = chars.next() {
+        if ch == '\u{1b}' && normalized[i..].starts_with("\u{1b}[") {
+            if let Some(end) = normalized[i..].find('m') {
+                let seq = &normalized[i + 2..i + end];
+                if !current_text.is_empty() {
+                    plain.push_str(&current_text);
+                    spans.push(StyledSpan {
+                        text: std::mem::take(&mut current_text),
+                        sgr: active.clone(),
+                    });
+                }
+                apply_sgr(&mut active, seq);
+                // Skip past the consumed escape sequence.
+                while let Some(&(j, _)) = chars.peek() {
+                    if j >= i + end + 1 {
+                        break;
+                    }
+                    chars.next();
+                }
+                continue;
+            }
+        }
+        current_text.push(ch);
+    }
+    if !current_text.is_empty() {
+        plain.push_str(&current_text);
+        spans.push(StyledSpan {
+            text: current_text,
+            sgr: active,
+        });
+    }
+
+    PaneLine { plain, spans }
+}
+
+fn apply_sgr(active: &mut Vec<String>, seq: &str) {
+    if seq.is_empty() {
+        active.clear();
+        return;
+    }
+    for code in seq.split(';') {
+        if code.is_empty() || code == "0" {
+            active.clear();
+        } else {
+            active.push(code.to_string());
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! Pluggable pane-capture backend. Status classification (`crate::detect`)
+//! only ever needs a pane's text content, so it's kept behind the
+//! [`PaneCapture`] trait instead of calling tmux directly — the production
+//! path ([`TmuxCapture`]) shells out, while the `fixture-capture` feature
+//! swaps in [`FixtureCapture`], which replays a recorded pane dump from
+//! disk. That's what lets a contributor turn a misdetected pane into a
+//! regression test: save the dump, point a fixture test at it, done.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Captures the current (or historical) text content of a tmux pane.
+pub trait PaneCapture {
+    /// Returns the pane's content with SGR escape sequences preserved, or
+    /// `None` if the pane couldn't be captured. When `scrollback_lines` is
+    /// `Some(n)`, `n` lines of history above the visible pane are included.
+    fn capture(&self, pane_id: &str, scrollback_lines: Option<u32>) -> Option<String>;
+}
+
+/// Captures a real tmux pane via `tmux capture-pane -e -p`, optionally
+/// widened with `-S -<n> -E -` to pull in scrollback history.
+pub struct TmuxCapture {
+    pub tmux_path: PathBuf,
+}
+
+impl PaneCapture for TmuxCapture {
+    fn capture(&self, pane_id: &str, scrollback_lines: Option<u32>) -> Option<String> {
+        let mut args = vec![
+            "capture-pane".to_string(),
+            "-t".to_string(),
+            pane_id.to_string(),
+        ];
+        if let Some(n) = scrollback_lines {
+            args.push("-S".to_string());
+            args.push(format!("-{n}"));
+            args.push("-E".to_string());
+            args.push("-".to_string());
+        }
+        args.push("-e".to_string());
+        args.push("-p".to_string());
+
+        let output = Command::new(&self.tmux_path)
+            .env_remove("TMPDIR")
+            .args(&args)
+            .output()
+            .ok()?;
+
+        if !output.status.success() {
+            return None;
+        }
+        Some(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+}
+
+/// Replays a recorded pane dump instead of talking to tmux. `pane_id` is
+/// treated as the fixture's file stem, so a test pointed at fixture
+/// `codex_plan_approval` reads `<fixture_dir>/codex_plan_approval.txt`.
+/// `scrollback_lines` is ignored — a fixture is a fixed snapshot, not a
+/// live scrollback buffer.
+#[cfg(feature = "fixture-capture")]
+pub struct FixtureCapture {
+    pub fixture_dir: PathBuf,
+}
+
+#[cfg(feature = "fixture-capture")]
+impl FixtureCapture {
+    pub fn new(fixture_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            fixture_dir: fixture_dir.into(),
+        }
+    }
+
+    /// Reads a fixture by name directly, without going through the
+    /// `PaneCapture`/thread-local plumbing — the shape a fixture-driven test
+    /// usually wants.
+    pub fn read(&self, name: &str) -> Option<String> {
+        std::fs::read_to_string(self.fixture_dir.join(format!("{name}.txt"))).ok()
+    }
+}
+
+#[cfg(feature = "fixture-capture")]
+impl PaneCapture for FixtureCapture {
+    fn capture(&self, pane_id: &str, _scrollback_lines: Option<u32>) -> Option<String> {
+        self.read(pane_id)
+    }
+}
+
+#[cfg(feature = "fixture-capture")]
+thread_local! {
+    static OVERRIDE: std::cell::RefCell<Option<Box<dyn PaneCapture>>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+/// Installs `capture` as the backend every [`capture_pane`] call on this
+/// thread uses for the duration of `f`, then restores the previous
+/// override. Only available under `fixture-capture`, so production builds
+/// can't pay for (or accidentally trip) the indirection.
+#[cfg(feature = "fixture-capture")]
+pub fn with_override<F: FnOnce() -> R, R>(capture: Box<dyn PaneCapture>, f: F) -> R {
+    let previous = OVERRIDE.with(|cell| cell.borrow_mut().replace(capture));
+    let result = f();
+    OVERRIDE.with(|cell| *cell.borrow_mut() = previous);
+    result
+}
+
+/// Captures `pane_id` through `backend`, unless a `fixture-capture` override
+/// has been installed on this thread via [`with_override`], in which case
+/// that takes precedence. Callers should hold a `TmuxCapture` as `backend`
+/// in production; tests can pass anything and rely on the override instead.
+pub fn capture_pane(
+    backend: &dyn PaneCapture,
+    pane_id: &str,
+    scrollback_lines: Option<u32>,
+) -> Option<String> {
+    #[cfg(feature = "fixture-capture")]
+    {
+        let overridden = OVERRIDE.with(|cell| {
+            cell.borrow()
+                .as_ref()
+                .map(|cap| cap.capture(pane_id, scrollback_lines))
+        });
+        if let Some(result) = overridden {
+            return result;
+        }
+    }
+    backend.capture(pane_id, scrollback_lines)
+}
+
+/// Convenience constructor for the production backend, given a resolved
+/// `tmux` binary path.
+pub fn tmux_capture(tmux_path: impl AsRef<Path>) -> TmuxCapture {
+    TmuxCapture {
+        tmux_path: tmux_path.as_ref().to_path_buf(),
+    }
+}
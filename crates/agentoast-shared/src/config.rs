@@ -23,6 +23,18 @@ pub fn db_path() -> PathBuf {
     data_dir().join("notifications.db")
 }
 
+/// Return the path to the toast control socket (see
+/// `native_toast::control_socket`). Prefers `$XDG_RUNTIME_DIR`, which is
+/// tmpfs-backed and scoped to the user session on Linux; macOS has no
+/// equivalent env var, so it falls back to `data_dir()` there.
+pub fn toast_socket_path() -> PathBuf {
+    if let Ok(runtime_dir) = std::env::var("XDG_RUNTIME_DIR") {
+        PathBuf::from(runtime_dir).join("agentoast-toast.sock")
+    } else {
+        data_dir().join("toast.sock")
+    }
+}
+
 /// Return XDG_CONFIG_HOME/agentoast.
 pub fn config_dir() -> PathBuf {
     if let Ok(xdg) = std::env::var("XDG_CONFIG_HOME") {
@@ -42,6 +54,350 @@ pub struct AppConfig {
     pub notification: NotificationConfig,
     #[serde(default)]
     pub keybinding: KeybindingConfig,
+    #[serde(default)]
+    pub updater: UpdaterConfig,
+    #[serde(default)]
+    pub agent_detection: AgentDetectionConfig,
+    #[serde(default)]
+    pub admin: AdminConfig,
+}
+
+/// Loopback HTTP API (`agentoast admin`) exposing the db module's read/clear
+/// functions to other tooling (editors, tmux status bars, scripts) without
+/// them opening the SQLite file directly. Off by default since it's a local
+/// network listener.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AdminConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_admin_port")]
+    pub port: u16,
+    /// Required in the `Authorization: Bearer <token>` header on every
+    /// request. Unset means no auth is enforced -- fine on a single-user
+    /// machine, but only safe because the listener is bound to 127.0.0.1.
+    #[serde(default)]
+    pub token: Option<String>,
+}
+
+impl Default for AdminConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: default_admin_port(),
+            token: None,
+        }
+    }
+}
+
+fn default_admin_port() -> u16 {
+    4318
+}
+
+/// Agent detection settings: the custom-agent rule registry consulted by the
+/// session scanner's `detect_agent`/`detect_agent_status` (seeded with the
+/// built-in Codex/Claude Code/OpenCode entries, overridable per key), plus
+/// the hysteresis window applied to the status each rule set produces.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AgentDetectionConfig {
+    /// Custom agents, keyed by agent type (e.g. "aider").
+    #[serde(default)]
+    pub custom: std::collections::HashMap<String, CustomAgentConfig>,
+    #[serde(default)]
+    pub hysteresis: HysteresisConfig,
+}
+
+/// Smooths a single-frame misread (a status bar that briefly shows stale
+/// text) into the pane's reported status. A transition into `Waiting`/`Idle`
+/// only takes effect once `m` of the last `k` raw detections agree; `Running`
+/// — including a real-time spinner hit — always takes effect immediately,
+/// since a spinner is a reliable "definitely running" signal not worth
+/// delaying.
+#[derive(Debug, Clone, Deserialize)]
+pub struct HysteresisConfig {
+    #[serde(default = "default_hysteresis_k")]
+    pub k: usize,
+    #[serde(default = "default_hysteresis_m")]
+    pub m: usize,
+}
+
+impl Default for HysteresisConfig {
+    fn default() -> Self {
+        Self {
+            k: default_hysteresis_k(),
+            m: default_hysteresis_m(),
+        }
+    }
+}
+
+fn default_hysteresis_k() -> usize {
+    3
+}
+
+fn default_hysteresis_m() -> usize {
+    2
+}
+
+/// Declarative pane-content rules for one custom agent.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CustomAgentConfig {
+    /// Process `comm` name(s) to match in the pane's descendant process tree
+    /// (e.g. "aider").
+    pub process_names: Vec<String>,
+    /// Lines matching any of these mark the agent as actively running.
+    #[serde(default)]
+    pub running_patterns: Vec<String>,
+    /// Lines matching any of these mark the agent as waiting on input
+    /// (elicitation/permission dialog) — takes priority over `prompt_patterns`.
+    /// Each rule can carry a `waiting_reason` label (e.g. "confirmation"),
+    /// surfaced alongside the `Waiting` status the same way `mode_patterns`
+    /// labels are.
+    #[serde(default)]
+    pub waiting_patterns: Vec<WaitingRule>,
+    /// Lines matching any of these mark the pane as back at a shell prompt
+    /// (idle, unless a pending notification makes it Waiting instead). Only
+    /// used when both `prompt_prefixes` and `prompt_suffixes` are empty.
+    #[serde(default)]
+    pub prompt_patterns: Vec<String>,
+    /// Prefix characters/strings (e.g. `"›"`) that mark the last
+    /// meaningful line as a prompt. When non-empty, this drives prompt
+    /// detection instead of `prompt_patterns`, via a bottom-up scan that
+    /// skips `footer_patterns` lines first (giving up at the 3rd
+    /// unrecognized line), mirroring the built-in Claude/Codex walk.
+    #[serde(default)]
+    pub prompt_prefixes: Vec<String>,
+    /// Substrings identifying TUI footer/status-bar lines to skip while
+    /// walking up from the bottom of the pane looking for a prompt.
+    #[serde(default)]
+    pub footer_patterns: Vec<String>,
+    /// `(pattern, mode label)` pairs, same shape as the built-in Claude Code
+    /// `MODE_PATTERNS` (e.g. `["plan mode on", "plan"]`).
+    #[serde(default)]
+    pub mode_patterns: Vec<(String, String)>,
+    /// Treat every running/waiting/mode pattern above as a regex instead of a
+    /// plain substring (`prompt_prefixes`/`footer_patterns` are always plain
+    /// substrings).
+    #[serde(default)]
+    pub regex: bool,
+    /// Pull this many lines of scrollback history into the capture instead
+    /// of just the visible pane (`capture-pane -S -N -E -`). Useful for TUIs
+    /// whose running/prompt markers can scroll out of view before the next
+    /// poll. Leave unset to capture only the visible pane.
+    #[serde(default)]
+    pub scrollback_lines: Option<u32>,
+    /// Regex matching the cursor that marks the currently highlighted option
+    /// in a numbered-choice elicitation dialog (e.g. Codex's `❯` before a
+    /// selected item). A match is treated as evidence of a selection dialog
+    /// on screen and yields `Waiting` with a `"selection"` reason, the same
+    /// priority as `waiting_patterns`.
+    #[serde(default)]
+    pub selection_cursor_regex: Option<String>,
+    /// Leading glyphs that mark a running-spinner line (e.g. Claude Code's
+    /// "✻ Thinking…"). When non-empty, a line starting with one of these
+    /// chars additionally counts as running if it also matches any
+    /// `spinner_running_patterns` — independent of `running_patterns`,
+    /// which matches anywhere in the line regardless of a spinner glyph.
+    #[serde(default)]
+    pub spinner_chars: Vec<char>,
+    /// Running substrings that only count on a line starting with a
+    /// `spinner_chars` glyph (e.g. the bare ellipsis in "✻ Thinking…").
+    /// Ignored when `spinner_chars` is empty.
+    #[serde(default)]
+    pub spinner_running_patterns: Vec<String>,
+    /// Suffixes (or, once trimmed, exact matches) that mark the last
+    /// meaningful line as a prompt, e.g. a shell's `"$ "`/`"%"`/`">"`.
+    /// Checked alongside `prompt_prefixes` after stripping a `│ ... │` box
+    /// border; either one being non-empty switches prompt detection away
+    /// from `prompt_patterns`, same as `prompt_prefixes` alone does today.
+    #[serde(default)]
+    pub prompt_suffixes: Vec<String>,
+    /// Extra skip-line predicates applied while walking up from the bottom
+    /// of the pane looking for a prompt, beyond `footer_patterns`' plain
+    /// substring match — for structural lines that aren't fixed text (a
+    /// numbered dialog option, a bare box-drawing separator, ...).
+    #[serde(default)]
+    pub skip_rules: Vec<SkipRule>,
+    /// SGR parameter codes (e.g. `"33"` for yellow) a `spinner_chars` line
+    /// must carry at least one of to count as running. Empty means no color
+    /// requirement — the plain-text behavior every built-in agent still uses,
+    /// since a capture without `-e` (or a fixture) never carries SGR codes.
+    #[serde(default)]
+    pub spinner_required_sgr: Vec<String>,
+    /// Require a `selection_cursor_regex` match to also carry SGR code `"7"`
+    /// (reverse video) before counting as a true highlighted selection
+    /// cursor, rather than treating any line that happens to match the regex
+    /// as one.
+    #[serde(default)]
+    pub selection_requires_highlight: bool,
+}
+
+/// One `skip_rules` predicate, checked against a trimmed pane line while
+/// walking up looking for a prompt; a match is skipped without counting
+/// against the unknown-line budget.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SkipRule {
+    /// Skip lines containing `pattern` anywhere.
+    Contains { pattern: String },
+    /// Skip lines beginning with `pattern`.
+    StartsWith { pattern: String },
+    /// Skip lines matching `pattern` as a regex.
+    Regex { pattern: String },
+    /// Skip lines consisting entirely of box-drawing characters (U+2500..U+257F).
+    IsSeparator,
+}
+
+impl SkipRule {
+    pub fn matches(&self, line: &str) -> bool {
+        match self {
+            SkipRule::Contains { pattern } => line.contains(pattern.as_str()),
+            SkipRule::StartsWith { pattern } => line.starts_with(pattern.as_str()),
+            SkipRule::Regex { pattern } => regex::Regex::new(pattern)
+                .map(|re| re.is_match(line))
+                .unwrap_or(false),
+            SkipRule::IsSeparator => {
+                !line.is_empty() && line.chars().all(|c| ('\u{2500}'..='\u{257F}').contains(&c))
+            }
+        }
+    }
+}
+
+/// One `waiting_patterns` rule: a pattern plus the reason label to surface
+/// when it matches (e.g. `{pattern: "enter to confirm", waiting_reason:
+/// "confirmation"}`).
+#[derive(Debug, Clone, Deserialize)]
+pub struct WaitingRule {
+    pub pattern: String,
+    #[serde(default)]
+    pub waiting_reason: Option<String>,
+}
+
+/// Built-in agent detection entries, seeded before the user's
+/// `[agent_detection.custom.*]` overrides (same key replaces the default).
+/// Codex, Claude Code, and OpenCode all ship here so every agent is detected
+/// through the same generic rule engine (`detect::classify_custom_status`)
+/// instead of one-off hardcoded status functions per agent.
+pub fn default_custom_agents() -> std::collections::HashMap<String, CustomAgentConfig> {
+    let mut defaults = std::collections::HashMap::new();
+    defaults.insert(
+        "codex".to_string(),
+        CustomAgentConfig {
+            process_names: vec!["codex".to_string()],
+            running_patterns: vec!["\\(\\d+s \u{2022} esc to interrupt\\)".to_string()],
+            waiting_patterns: vec![
+                WaitingRule {
+                    pattern: "enter to submit answer".to_string(),
+                    waiting_reason: Some("confirmation".to_string()),
+                },
+                WaitingRule {
+                    pattern: "enter to confirm".to_string(),
+                    waiting_reason: Some("confirmation".to_string()),
+                },
+            ],
+            prompt_patterns: Vec::new(),
+            prompt_prefixes: vec!["\u{203A}".to_string()],
+            footer_patterns: vec![
+                "for shortcuts".to_string(),
+                "context left".to_string(),
+                "background terminal running".to_string(),
+                "/ps to view".to_string(),
+                "/clean to close".to_string(),
+            ],
+            mode_patterns: Vec::new(),
+            regex: true,
+            scrollback_lines: None,
+            selection_cursor_regex: None,
+            spinner_chars: Vec::new(),
+            spinner_running_patterns: Vec::new(),
+            prompt_suffixes: Vec::new(),
+            skip_rules: Vec::new(),
+            spinner_required_sgr: Vec::new(),
+            selection_requires_highlight: false,
+        },
+    );
+    defaults.insert(
+        "claude-code".to_string(),
+        CustomAgentConfig {
+            process_names: vec!["claude".to_string()],
+            // "4 files +20 -0 · esc to interrupt" — status-bar suffix, no
+            // spinner glyph required.
+            running_patterns: vec!["\u{00B7} esc to interrupt".to_string()],
+            waiting_patterns: vec![WaitingRule {
+                pattern: "Enter to select".to_string(),
+                waiting_reason: None,
+            }],
+            prompt_patterns: Vec::new(),
+            prompt_prefixes: vec!["\u{276F}".to_string()], // ❯ (starship / Claude Code prompt)
+            footer_patterns: vec![
+                "for shortcuts".to_string(),
+                "shift+tab to cycle".to_string(),
+                "ctrl+".to_string(),
+                "ctrl-".to_string(),
+                "Context left until auto-compact".to_string(),
+            ],
+            mode_patterns: vec![
+                ("plan mode on".to_string(), "plan".to_string()),
+                ("bypass permissions on".to_string(), "bypass".to_string()),
+                ("accept edits on".to_string(), "accept".to_string()),
+            ],
+            regex: false,
+            scrollback_lines: None,
+            selection_cursor_regex: None,
+            spinner_chars: vec!['\u{2722}', '\u{2735}', '\u{2736}', '\u{273B}', '\u{00B7}'],
+            spinner_running_patterns: vec!["esc to interrupt".to_string(), "\u{2026}".to_string()],
+            prompt_suffixes: vec!["$ ".to_string(), "%".to_string(), ">".to_string()],
+            skip_rules: vec![
+                SkipRule::StartsWith {
+                    pattern: "\u{23F5}".to_string(), // ⏵ mode indicator (bypass/plan)
+                },
+                SkipRule::StartsWith {
+                    pattern: "\u{23F8}".to_string(), // ⏸ mode indicator (plan)
+                },
+                SkipRule::Regex {
+                    pattern: r"^\d+\. ".to_string(), // numbered elicitation option
+                },
+                SkipRule::Regex {
+                    pattern: r"^\d+.*file.*[+-]".to_string(), // "4 files +20 -0"
+                },
+                SkipRule::IsSeparator,
+            ],
+            // The real spinner is rendered in Claude Code's accent color, but
+            // without a colored capture on hand to confirm the exact SGR code
+            // it's left unenforced here rather than guessed at.
+            spinner_required_sgr: Vec::new(),
+            selection_requires_highlight: false,
+        },
+    );
+    defaults.insert(
+        "opencode".to_string(),
+        CustomAgentConfig {
+            process_names: vec!["opencode".to_string()],
+            running_patterns: vec!["esc to interrupt".to_string()],
+            waiting_patterns: vec![
+                WaitingRule {
+                    pattern: "Permission Required".to_string(),
+                    waiting_reason: Some("confirmation".to_string()),
+                },
+                WaitingRule {
+                    pattern: "Enter to select".to_string(),
+                    waiting_reason: None,
+                },
+            ],
+            prompt_patterns: Vec::new(),
+            prompt_prefixes: vec![">".to_string()],
+            footer_patterns: vec!["for shortcuts".to_string(), "to navigate".to_string()],
+            mode_patterns: Vec::new(),
+            regex: false,
+            scrollback_lines: None,
+            selection_cursor_regex: None,
+            spinner_chars: Vec::new(),
+            spinner_running_patterns: Vec::new(),
+            prompt_suffixes: Vec::new(),
+            skip_rules: vec![SkipRule::IsSeparator],
+            spinner_required_sgr: Vec::new(),
+            selection_requires_highlight: false,
+        },
+    );
+    defaults
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -50,6 +406,34 @@ pub struct ToastConfig {
     pub duration_ms: u64,
     #[serde(default)]
     pub persistent: bool,
+    /// Keep the toast (and panel) visible across macOS Spaces and over
+    /// full-screen apps, instead of only on the Space it was created on.
+    #[serde(default = "default_true")]
+    pub visible_on_all_workspaces: bool,
+    /// Which display to anchor the toast to in multi-monitor setups.
+    #[serde(default)]
+    pub screen: ScreenPolicy,
+    /// Keys for navigating the toast's notification queue while it's
+    /// visible.
+    #[serde(default)]
+    pub keys: ToastKeybindConfig,
+    /// How long a press on the toast card must be held before it counts as
+    /// a long-press (snooze) instead of a click (focus terminal).
+    #[serde(default = "default_toast_hold_ms")]
+    pub hold_ms: u64,
+    /// Maximum number of queued notifications shown stacked at once; the
+    /// rest collapse into a "+k more" pill. 1 shows only the current one.
+    #[serde(default = "default_toast_stack_size")]
+    pub stack_size: usize,
+    /// Which screen corner the toast is anchored to.
+    #[serde(default)]
+    pub anchor: ToastAnchor,
+    /// Horizontal distance in points from the anchored edge.
+    #[serde(default = "default_toast_margin")]
+    pub margin_x: f64,
+    /// Vertical distance in points from the anchored edge.
+    #[serde(default = "default_toast_margin")]
+    pub margin_y: f64,
 }
 
 impl Default for ToastConfig {
@@ -57,10 +441,117 @@ impl Default for ToastConfig {
         Self {
             duration_ms: default_toast_duration(),
             persistent: false,
+            visible_on_all_workspaces: true,
+            screen: ScreenPolicy::default(),
+            keys: ToastKeybindConfig::default(),
+            hold_ms: default_toast_hold_ms(),
+            stack_size: default_toast_stack_size(),
+            anchor: ToastAnchor::default(),
+            margin_x: default_toast_margin(),
+            margin_y: default_toast_margin(),
         }
     }
 }
 
+fn default_toast_stack_size() -> usize {
+    3
+}
+
+fn default_toast_margin() -> f64 {
+    16.0
+}
+
+fn default_toast_hold_ms() -> u64 {
+    500
+}
+
+/// Keys the toast's own `NSEvent::KeyDown` monitor matches against to move
+/// through a multi-item queue -- next/previous, dismiss the current item, or
+/// dismiss the whole queue. Separate from `[keybinding]`/`KeyAction` because
+/// those register OS-wide shortcuts via `tauri_plugin_global_shortcut`; the
+/// toast panel is `becomesKeyOnlyIfNeeded` and never becomes key, so its
+/// bindings only make sense while it's on screen and are matched locally
+/// instead. Arrow keys (`"up"`/`"down"`) are accepted alongside plain
+/// characters.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ToastKeybindConfig {
+    #[serde(default = "default_toast_key_next")]
+    pub next: String,
+    #[serde(default = "default_toast_key_previous")]
+    pub previous: String,
+    #[serde(default = "default_toast_key_dismiss")]
+    pub dismiss: String,
+    #[serde(default = "default_toast_key_dismiss_all")]
+    pub dismiss_all: String,
+}
+
+impl Default for ToastKeybindConfig {
+    fn default() -> Self {
+        Self {
+            next: default_toast_key_next(),
+            previous: default_toast_key_previous(),
+            dismiss: default_toast_key_dismiss(),
+            dismiss_all: default_toast_key_dismiss_all(),
+        }
+    }
+}
+
+fn default_toast_key_next() -> String {
+    "j".to_string()
+}
+
+fn default_toast_key_previous() -> String {
+    "k".to_string()
+}
+
+fn default_toast_key_dismiss() -> String {
+    "x".to_string()
+}
+
+fn default_toast_key_dismiss_all() -> String {
+    "d".to_string()
+}
+
+/// Which screen `native_toast::position_at_top_right` (or the Wayland
+/// backend's equivalent layer-surface output selection) anchors the toast
+/// to, for multi-monitor setups.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum ScreenPolicy {
+    /// The screen containing the mouse cursor.
+    Cursor,
+    /// The screen showing the key/main window (falls back to `Cursor` if no
+    /// window is currently key, e.g. nothing has been focused yet).
+    KeyWindow,
+    /// A fixed `NSScreen::screens()` index, for pinning the toast to a
+    /// specific monitor regardless of focus; out-of-range falls back to the
+    /// main screen.
+    Fixed { index: usize },
+}
+
+impl Default for ScreenPolicy {
+    fn default() -> Self {
+        ScreenPolicy::Cursor
+    }
+}
+
+/// Which corner of the chosen screen `native_toast::position_at_top_right`
+/// anchors the toast to, offset by `[toast].margin_x`/`margin_y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ToastAnchor {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl Default for ToastAnchor {
+    fn default() -> Self {
+        ToastAnchor::TopRight
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct NotificationConfig {
     #[serde(default)]
@@ -69,6 +560,12 @@ pub struct NotificationConfig {
     pub filter_notified_only: bool,
     #[serde(default)]
     pub agents: AgentsConfig,
+    #[serde(default)]
+    pub delivery: DeliveryConfig,
+    #[serde(default)]
+    pub status_rules: StatusNotifyConfig,
+    #[serde(default)]
+    pub snooze: SnoozeConfig,
 }
 
 impl Default for NotificationConfig {
@@ -77,36 +574,435 @@ impl Default for NotificationConfig {
             muted: false,
             filter_notified_only: default_filter_notified_only(),
             agents: AgentsConfig::default(),
+            delivery: DeliveryConfig::default(),
+            status_rules: StatusNotifyConfig::default(),
+            snooze: SnoozeConfig::default(),
+        }
+    }
+}
+
+/// "Remind me later" settings for `db::snooze_notification` /
+/// `db::get_due_snoozed`. `default_interval` is used when a CLI/command
+/// snoozes a notification without specifying its own duration.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SnoozeConfig {
+    /// Human interval string (see `interval::parse_interval`), e.g. `"15m"`.
+    #[serde(default = "default_snooze_interval")]
+    pub default_interval: String,
+    /// How often the background reader checks for due snoozes.
+    #[serde(default = "default_snooze_poll_interval_secs")]
+    pub poll_interval_secs: u64,
+}
+
+impl Default for SnoozeConfig {
+    fn default() -> Self {
+        Self {
+            default_interval: default_snooze_interval(),
+            poll_interval_secs: default_snooze_poll_interval_secs(),
         }
     }
 }
 
+fn default_snooze_interval() -> String {
+    "15m".to_string()
+}
+
+fn default_snooze_poll_interval_secs() -> u64 {
+    30
+}
+
 fn default_filter_notified_only() -> bool {
     false
 }
 
+/// Fires a configured notification (and optional sound) when a tmux pane's
+/// `AgentStatus` makes a meaningful transition (e.g. Running→Waiting), modeled
+/// after dunst's per-urgency rule matching.
+#[derive(Debug, Clone, Deserialize)]
+pub struct StatusNotifyConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// How often the pane list is re-polled to detect transitions.
+    #[serde(default = "default_status_poll_interval_secs")]
+    pub poll_interval_secs: u64,
+    /// Tried top-to-bottom; the first rule whose `transition` and optional
+    /// filters match wins, mirroring `GenericAgentConfig::rules`.
+    #[serde(default)]
+    pub rules: Vec<StatusTransitionRule>,
+}
+
+impl Default for StatusNotifyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            poll_interval_secs: default_status_poll_interval_secs(),
+            rules: Vec::new(),
+        }
+    }
+}
+
+fn default_status_poll_interval_secs() -> u64 {
+    3
+}
+
+/// One rule in the status-transition subsystem.
+#[derive(Debug, Clone, Deserialize)]
+pub struct StatusTransitionRule {
+    /// "running_to_waiting" or "running_to_idle".
+    pub transition: String,
+    /// Matches only panes running this agent type (e.g. "claude-code").
+    #[serde(default)]
+    pub agent_type: Option<String>,
+    /// Matches only panes in this repo (by the group's `repo_name`).
+    #[serde(default)]
+    pub repo_name: Option<String>,
+    /// Matches only panes whose `agent_modes` contains this value (e.g. "plan").
+    #[serde(default)]
+    pub agent_mode: Option<String>,
+    /// dunst-style urgency: "critical", "normal", or "low".
+    #[serde(default = "default_status_urgency")]
+    pub urgency: String,
+    /// Summary template; supports `{agent_type}`, `{repo_name}`, `{branch}`,
+    /// and `{window_name}` placeholders.
+    pub summary: String,
+    /// Shell command run (detached) on match, e.g. a `playsound`-style sound
+    /// cue. `{urgency}` is substituted before execution.
+    #[serde(default)]
+    pub sound: Option<String>,
+}
+
+fn default_status_urgency() -> String {
+    "normal".to_string()
+}
+
+/// Delivery backends a notification is fanned out to beyond the SQLite feed.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DeliveryConfig {
+    /// Cross-platform desktop toast (separate from the Tauri panel).
+    #[serde(default)]
+    pub desktop: bool,
+    /// Fall back to the OS notification center when the Tauri app's toast
+    /// window isn't available (headless build, or a platform `toast::show`
+    /// doesn't support). Unlike `desktop`, which the CLI hook path always
+    /// fires regardless of whether the GUI is running, this only kicks in
+    /// as a substitute for a toast the GUI itself couldn't display.
+    #[serde(default)]
+    pub native: bool,
+    #[serde(default)]
+    pub email: Option<EmailDeliveryConfig>,
+    #[serde(default)]
+    pub apns: Option<ApnsDeliveryConfig>,
+    #[serde(default)]
+    pub webhook: Option<WebhookDeliveryConfig>,
+}
+
+/// Outbound webhook delivery: POSTs the notification as JSON to `url`,
+/// optionally signed with `secret` per the Standard Webhooks scheme
+/// (`webhook-id`/`webhook-timestamp`/`webhook-signature` headers, see
+/// `notifier::WebhookNotifier`). This is not the hex body HMAC the inbound
+/// `webhook` hook verifies.
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebhookDeliveryConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    pub url: String,
+    #[serde(default)]
+    pub secret: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EmailDeliveryConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    pub host: String,
+    #[serde(default = "default_smtp_port")]
+    pub port: u16,
+    #[serde(default)]
+    pub username: String,
+    #[serde(default)]
+    pub password: String,
+    pub from: String,
+    pub to: String,
+    #[serde(default)]
+    pub tls: SmtpTlsMode,
+}
+
+fn default_smtp_port() -> u16 {
+    587
+}
+
+/// How [`EmailDeliveryConfig`] negotiates TLS with the SMTP relay.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SmtpTlsMode {
+    /// Connect in plaintext, then upgrade via the `STARTTLS` command (port 587).
+    #[default]
+    Starttls,
+    /// Wrap the connection in TLS from the first byte (port 465).
+    Implicit,
+}
+
+/// Apple Push Notification delivery, for reaching a phone when the
+/// developer is away from the terminal. Auth is token-based (ES256 JWT
+/// over `team_id`/`key_id`, signed with the `.p8` key at `signing_key_path`).
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApnsDeliveryConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Path to the APNs auth key (.p8) downloaded from the Apple Developer portal.
+    pub signing_key_path: String,
+    pub team_id: String,
+    pub key_id: String,
+    /// Bundle ID of the receiving app; sent as `apns-topic`.
+    pub topic: String,
+    /// Device tokens to deliver to (one push per token).
+    pub device_tokens: Vec<String>,
+    #[serde(default)]
+    pub sandbox: bool,
+}
+
 fn default_toast_duration() -> u64 {
     4000
 }
 
+/// An app action a `[keybinding]` entry can be bound to. New actions go
+/// here, in `ALL`, and in `KeybindingConfig::raw`/the UI layer's dispatch
+/// table — adding one doesn't otherwise change how binding, validation, or
+/// conflict detection work.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyAction {
+    TogglePanel,
+    ToggleMute,
+    ClearAll,
+    FocusLatest,
+    ToggleFilterNotifiedOnly,
+    FocusTerminal,
+    DismissToast,
+    OpenConfig,
+}
+
+impl KeyAction {
+    pub const ALL: [KeyAction; 8] = [
+        KeyAction::TogglePanel,
+        KeyAction::ToggleMute,
+        KeyAction::ClearAll,
+        KeyAction::FocusLatest,
+        KeyAction::ToggleFilterNotifiedOnly,
+        KeyAction::FocusTerminal,
+        KeyAction::DismissToast,
+        KeyAction::OpenConfig,
+    ];
+
+    /// The `[keybinding]` TOML key this action is configured under.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            KeyAction::TogglePanel => "toggle_panel",
+            KeyAction::ToggleMute => "toggle_mute",
+            KeyAction::ClearAll => "clear_all",
+            KeyAction::FocusLatest => "focus_latest",
+            KeyAction::ToggleFilterNotifiedOnly => "toggle_filter_notified_only",
+            KeyAction::FocusTerminal => "focus_terminal",
+            KeyAction::DismissToast => "dismiss_toast",
+            KeyAction::OpenConfig => "open_config",
+        }
+    }
+}
+
+/// Modifiers accepted in a keybinding chord. `option`/`cmd` are accepted as
+/// macOS-familiar aliases for `alt`/`super`.
+const VALID_MODIFIERS: &[&str] = &["ctrl", "shift", "alt", "option", "super", "cmd"];
+
+/// A parsed `modifier+modifier+key` chord, e.g. `"super+ctrl+n"`. Modifiers
+/// are lowercased and kept in written order, so the derived `PartialEq` is
+/// order-sensitive; conflict checks compare [`Chord::normalized`] instead,
+/// which is order-independent, and a chord's `Display` reproduces the order
+/// it was written in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chord {
+    pub modifiers: Vec<String>,
+    pub key: String,
+}
+
+impl Chord {
+    /// Parses `raw`, rejecting any modifier outside `VALID_MODIFIERS`.
+    fn parse(raw: &str) -> Result<Chord, String> {
+        let mut parts: Vec<&str> = raw.split('+').map(str::trim).collect();
+        let Some(key) = parts.pop().filter(|k| !k.is_empty()) else {
+            return Err(format!("{raw:?} has no key"));
+        };
+        let mut modifiers = Vec::with_capacity(parts.len());
+        for modifier in parts {
+            let modifier = modifier.to_lowercase();
+            if !VALID_MODIFIERS.contains(&modifier.as_str()) {
+                return Err(format!("unknown modifier {modifier:?} in {raw:?}"));
+            }
+            modifiers.push(modifier);
+        }
+        Ok(Chord {
+            modifiers,
+            key: key.to_lowercase(),
+        })
+    }
+
+    /// Modifiers sorted, so two chords binding the same keys in a different
+    /// written order still compare equal for conflict detection.
+    fn normalized(&self) -> (Vec<String>, String) {
+        let mut modifiers = self.modifiers.clone();
+        modifiers.sort();
+        (modifiers, self.key.clone())
+    }
+}
+
+impl std::fmt::Display for Chord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for modifier in &self.modifiers {
+            write!(f, "{modifier}+")?;
+        }
+        write!(f, "{}", self.key)
+    }
+}
+
+/// Parsed, validated keybindings — the typed counterpart to
+/// `KeybindingConfig`'s raw strings, built once via
+/// `KeybindingConfig::parse` so the UI layer dispatches on `KeyAction`
+/// instead of re-parsing (and re-validating) a chord string per shortcut.
+#[derive(Debug, Clone, Default)]
+pub struct Keybindings {
+    bindings: std::collections::HashMap<KeyAction, Chord>,
+}
+
+impl Keybindings {
+    pub fn get(&self, action: KeyAction) -> Option<&Chord> {
+        self.bindings.get(&action)
+    }
+}
+
+/// Named global keybindings, generalized into an action→chord map instead of
+/// one dedicated field per shortcut (`toggle_panel` was the only action
+/// exposed before). Each entry is a `modifier+key` chord string, or empty to
+/// disable that action; `KeybindingConfig::parse` validates modifiers and
+/// resolves conflicts into a typed `Keybindings`.
 #[derive(Debug, Clone, Deserialize)]
 pub struct KeybindingConfig {
     #[serde(default = "default_toggle_panel")]
     pub toggle_panel: String,
+    #[serde(default)]
+    pub toggle_mute: String,
+    #[serde(default)]
+    pub clear_all: String,
+    #[serde(default)]
+    pub focus_latest: String,
+    #[serde(default)]
+    pub toggle_filter_notified_only: String,
+    #[serde(default)]
+    pub focus_terminal: String,
+    #[serde(default)]
+    pub dismiss_toast: String,
+    #[serde(default)]
+    pub open_config: String,
 }
 
 impl Default for KeybindingConfig {
     fn default() -> Self {
         Self {
             toggle_panel: default_toggle_panel(),
+            toggle_mute: String::new(),
+            clear_all: String::new(),
+            focus_latest: String::new(),
+            toggle_filter_notified_only: String::new(),
+            focus_terminal: String::new(),
+            dismiss_toast: String::new(),
+            open_config: String::new(),
         }
     }
 }
 
+impl KeybindingConfig {
+    fn raw(&self, action: KeyAction) -> &str {
+        match action {
+            KeyAction::TogglePanel => &self.toggle_panel,
+            KeyAction::ToggleMute => &self.toggle_mute,
+            KeyAction::ClearAll => &self.clear_all,
+            KeyAction::FocusLatest => &self.focus_latest,
+            KeyAction::ToggleFilterNotifiedOnly => &self.toggle_filter_notified_only,
+            KeyAction::FocusTerminal => &self.focus_terminal,
+            KeyAction::DismissToast => &self.dismiss_toast,
+            KeyAction::OpenConfig => &self.open_config,
+        }
+    }
+
+    /// Parses every configured chord in `KeyAction::ALL` order, validating
+    /// modifiers and rejecting a chord that collides with one already
+    /// accepted (the earlier action in `ALL` wins). Invalid and conflicting
+    /// entries are logged and left unbound rather than failing config load.
+    pub fn parse(&self) -> Keybindings {
+        let mut bindings = std::collections::HashMap::new();
+        let mut seen: std::collections::HashMap<(Vec<String>, String), KeyAction> =
+            std::collections::HashMap::new();
+
+        for action in KeyAction::ALL {
+            let raw = self.raw(action);
+            if raw.is_empty() {
+                continue;
+            }
+            let chord = match Chord::parse(raw) {
+                Ok(c) => c,
+                Err(e) => {
+                    log::warn!("Invalid keybinding for {}: {}", action.as_str(), e);
+                    continue;
+                }
+            };
+            let normalized = chord.normalized();
+            if let Some(existing) = seen.get(&normalized) {
+                log::warn!(
+                    "Keybinding {:?} for {} conflicts with {}, ignoring",
+                    raw,
+                    action.as_str(),
+                    existing.as_str()
+                );
+                continue;
+            }
+            seen.insert(normalized, action);
+            bindings.insert(action, chord);
+        }
+
+        Keybindings { bindings }
+    }
+}
+
 fn default_toggle_panel() -> String {
     "super+ctrl+n".to_string()
 }
 
+/// Where and how often the app checks for new releases. The periodic check
+/// runs on the same background task infrastructure as the DB watcher
+/// (`watcher::start`); set `auto_check_interval_secs` to 0 to disable it and
+/// rely on the tray's "Check for Updates…" item only.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdaterConfig {
+    #[serde(default = "default_update_feed_url")]
+    pub feed_url: String,
+    #[serde(default = "default_update_check_interval_secs")]
+    pub auto_check_interval_secs: u64,
+}
+
+impl Default for UpdaterConfig {
+    fn default() -> Self {
+        Self {
+            feed_url: default_update_feed_url(),
+            auto_check_interval_secs: default_update_check_interval_secs(),
+        }
+    }
+}
+
+fn default_update_feed_url() -> String {
+    "https://github.com/shuntaka9576/agentoast/releases/latest/download/latest.json".to_string()
+}
+
+fn default_update_check_interval_secs() -> u64 {
+    21_600
+}
+
 #[derive(Debug, Clone, Default, Deserialize)]
 pub struct AgentsConfig {
     #[serde(default)]
@@ -115,6 +1011,85 @@ pub struct AgentsConfig {
     pub codex: CodexHookConfig,
     #[serde(default)]
     pub opencode: OpenCodeHookConfig,
+    #[serde(default)]
+    pub webhook: WebhookHookConfig,
+    /// Config-declared agents, keyed by name, handled by `agentoast hook generic <name>`
+    /// instead of a dedicated Rust module.
+    #[serde(default)]
+    pub generic: std::collections::HashMap<String, GenericAgentConfig>,
+}
+
+/// Declarative config for a coding agent that isn't worth a dedicated hook
+/// module: every field the hook needs is resolved from the event JSON via a
+/// dot-separated path (e.g. "status.type"), mirroring the nested lookup
+/// OpenCode's `session.status` idle check already does.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct GenericAgentConfig {
+    /// Dot-separated path to the event name, e.g. "status.type".
+    pub event_field: String,
+    /// Maps an event value to the badge/color shown for it. Events not
+    /// present here are ignored.
+    #[serde(default)]
+    pub event_map: std::collections::HashMap<String, GenericEventMapping>,
+    /// Dot-separated path to the working directory, used to resolve repo name
+    /// and branch via git. Omit if the event carries no directory.
+    #[serde(default)]
+    pub cwd_field: Option<String>,
+    /// Dot-separated path to the notification body text.
+    #[serde(default)]
+    pub body_field: Option<String>,
+    #[serde(default)]
+    pub focus_events: Vec<String>,
+    /// Rule list evaluated top-to-bottom against the event JSON; the first
+    /// matching rule wins and supersedes `event_field`/`event_map` below. Lets
+    /// an agent be wired up on any field (not just one), with its own
+    /// icon/body path per rule, instead of a single flat event->badge map.
+    #[serde(default)]
+    pub rules: Vec<GenericRule>,
+    #[serde(default)]
+    pub dedup: DedupConfig,
+    /// Delivery backend names to fan out to. Empty means "use every enabled backend".
+    #[serde(default)]
+    pub channels: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GenericEventMapping {
+    pub badge: String,
+    #[serde(default = "default_generic_badge_color")]
+    pub badge_color: String,
+}
+
+/// One entry in a `GenericAgentConfig`'s `rules` list. Rules are tried in
+/// order; the first whose `field` resolves to `equals` is used to build the
+/// notification, letting a single agent match on more than one JSON field
+/// without juggling several flat `event_map`s.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GenericRule {
+    /// Dot-separated path into the event JSON, e.g. "status.type".
+    pub field: String,
+    /// Value `field` must equal (as a JSON string) for this rule to match.
+    pub equals: String,
+    pub badge: String,
+    #[serde(default = "default_generic_badge_color")]
+    pub badge_color: String,
+    /// Icon preset name (see `IconType::from_str`).
+    #[serde(default = "default_generic_icon")]
+    pub icon: String,
+    /// Dot-separated path to the notification body, overriding the agent's
+    /// top-level `body_field` for this rule.
+    #[serde(default)]
+    pub body_field: Option<String>,
+    #[serde(default)]
+    pub focus: bool,
+}
+
+fn default_generic_badge_color() -> String {
+    "gray".to_string()
+}
+
+fn default_generic_icon() -> String {
+    "agentoast".to_string()
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -123,6 +1098,12 @@ pub struct ClaudeHookConfig {
     pub events: Vec<String>,
     #[serde(default)]
     pub focus_events: Vec<String>,
+    #[serde(default)]
+    pub dedup: DedupConfig,
+    /// Delivery backend names to fan out to (see `[notification.delivery]`).
+    /// Empty means "use every enabled backend".
+    #[serde(default)]
+    pub channels: Vec<String>,
 }
 
 impl Default for ClaudeHookConfig {
@@ -130,6 +1111,8 @@ impl Default for ClaudeHookConfig {
         Self {
             events: default_claude_events(),
             focus_events: Vec::new(),
+            dedup: DedupConfig::default(),
+            channels: Vec::new(),
         }
     }
 }
@@ -142,6 +1125,11 @@ pub struct CodexHookConfig {
     pub focus_events: Vec<String>,
     #[serde(default = "default_true")]
     pub include_body: bool,
+    #[serde(default)]
+    pub dedup: DedupConfig,
+    /// Delivery backend names to fan out to. Empty means "use every enabled backend".
+    #[serde(default)]
+    pub channels: Vec<String>,
 }
 
 impl Default for CodexHookConfig {
@@ -150,6 +1138,8 @@ impl Default for CodexHookConfig {
             events: default_codex_events(),
             focus_events: Vec::new(),
             include_body: true,
+            dedup: DedupConfig::default(),
+            channels: Vec::new(),
         }
     }
 }
@@ -164,6 +1154,11 @@ pub struct OpenCodeHookConfig {
     pub events: Vec<String>,
     #[serde(default)]
     pub focus_events: Vec<String>,
+    #[serde(default)]
+    pub dedup: DedupConfig,
+    /// Delivery backend names to fan out to. Empty means "use every enabled backend".
+    #[serde(default)]
+    pub channels: Vec<String>,
 }
 
 impl Default for OpenCodeHookConfig {
@@ -171,10 +1166,52 @@ impl Default for OpenCodeHookConfig {
         Self {
             events: default_opencode_events(),
             focus_events: Vec::new(),
+            dedup: DedupConfig::default(),
+            channels: Vec::new(),
+        }
+    }
+}
+
+/// Inbound webhook hook (e.g. a GitHub push payload). Unlike the other
+/// agents, events arrive over HTTP rather than via stdin/argv, so each
+/// sender is identified by a named shared secret instead of an event list.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct WebhookHookConfig {
+    /// Named secrets, e.g. `[notification.agents.webhook.secrets] github = "..."`.
+    /// The secret name is passed alongside the signature so multiple senders
+    /// can be distinguished.
+    #[serde(default)]
+    pub secrets: std::collections::HashMap<String, String>,
+}
+
+/// Coalescing window for duplicate notifications fired in quick succession
+/// (e.g. an agent retrying the same hook event).
+#[derive(Debug, Clone, Deserialize)]
+pub struct DedupConfig {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    #[serde(default = "default_dedup_window_secs")]
+    pub window_secs: u64,
+    /// `skip` (default) drops the duplicate entirely; `coalesce` keeps the
+    /// original row, bumps its `coalesce_count`, and refreshes its timestamp.
+    #[serde(default)]
+    pub mode: crate::models::DedupMode,
+}
+
+impl Default for DedupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            window_secs: default_dedup_window_secs(),
+            mode: crate::models::DedupMode::default(),
         }
     }
 }
 
+fn default_dedup_window_secs() -> u64 {
+    10
+}
+
 fn default_opencode_events() -> Vec<String> {
     vec![
         "session.status".to_string(),
@@ -247,6 +1284,39 @@ fn default_config_template() -> &'static str {
 # Keep toast visible until clicked (default: false)
 # persistent = false
 
+# Keep the toast/panel visible across all Spaces and over full-screen apps (default: true)
+# visible_on_all_workspaces = true
+
+# Which display to anchor the toast to in multi-monitor setups (default: cursor)
+# mode = "cursor" follows the screen containing the pointer
+# mode = "key_window" follows the screen showing the key/main window
+# mode = "fixed", index = 0 pins it to a specific NSScreen::screens() index
+# [toast.screen]
+# mode = "cursor"
+
+# Keys for moving through a queued toast while it's visible (defaults shown)
+# [toast.keys]
+# next = "j"
+# previous = "k"
+# dismiss = "x"
+# dismiss_all = "d"
+
+# How long (ms) a press on the toast card must be held to snooze instead of
+# focusing the terminal (default: 500)
+# hold_ms = 500
+
+# Max notifications shown stacked at once; the rest collapse into a "+k more"
+# pill (default: 3). 1 shows only the current notification.
+# stack_size = 3
+
+# Which screen corner to anchor the toast to (default: top_right)
+# anchor = "top_left" | "top_right" | "bottom_left" | "bottom_right"
+# anchor = "top_right"
+
+# Distance in points from the anchored edge (defaults: 16, 16)
+# margin_x = 16
+# margin_y = 16
+
 # Notification settings
 [notification]
 # Mute all notifications (default: false)
@@ -255,6 +1325,40 @@ fn default_config_template() -> &'static str {
 # Show only groups with notifications (default: false)
 # filter_notified_only = false
 
+# Delivery backends notifications are fanned out to, beyond the SQLite feed the panel polls
+[notification.delivery]
+# Cross-platform desktop toast, separate from the Tauri panel (default: false)
+# desktop = false
+
+# Fall back to the OS notification center when the Tauri toast window isn't
+# available, e.g. a headless build or a non-macOS platform (default: false)
+# native = false
+
+# [notification.delivery.email]
+# enabled = false
+# host = "smtp.example.com"
+# port = 587
+# username = "bot@example.com"
+# password = "replace-with-an-app-password"
+# from = "bot@example.com"
+# to = "you@example.com"
+# TLS mode: "starttls" (default, matches port 587) or "implicit" (matches port 465)
+# tls = "starttls"
+
+# [notification.delivery.webhook]
+# enabled = false
+# url = "https://example.com/agentoast-webhook"
+# Standard Webhooks-style HMAC-SHA256 signing secret (base64); omit to send unsigned
+# secret = "replace-with-a-base64-secret"
+
+# "Remind me later" settings
+[notification.snooze]
+# Duration used when snoozing without specifying one, e.g. "15m", "2h30m", "1d"
+# default_interval = "15m"
+
+# How often (seconds) the background reader checks for due snoozes
+# poll_interval_secs = 30
+
 # Claude Code agent settings
 [notification.agents.claude]
 # Events that trigger notifications
@@ -266,6 +1370,10 @@ fn default_config_template() -> &'static str {
 # These events set force_focus=true, causing silent terminal focus without toast (when not muted)
 # focus_events = []
 
+# Delivery backends to fan this agent's notifications out to (see [notification.delivery])
+# Empty (default) means every enabled backend; names: "desktop", "email", "apns", "webhook"
+# channels = []
+
 # Codex agent settings
 [notification.agents.codex]
 # Events that trigger notifications
@@ -287,13 +1395,138 @@ fn default_config_template() -> &'static str {
 # Events that auto-focus the terminal (default: none)
 # focus_events = []
 
-# Keyboard shortcuts
-[keybinding]
-# Shortcut to toggle the notification panel (default: super+ctrl+n)
+# Inbound webhook settings (e.g. a GitHub push payload delivered via `agentoast hook webhook`)
+[notification.agents.webhook]
+# Named shared secrets, one per sender, used to verify the X-Hub-Signature-256-style header
+# secrets = { github = "replace-with-a-long-random-secret" }
+
+# Config-only agent onboarding: declare a new agent here instead of adding a Rust module,
+# then invoke it with `agentoast hook generic <name>`
+# [notification.agents.generic.my-agent]
+# event_field = "status.type"
+# cwd_field = "cwd"
+# body_field = "message"
+# [notification.agents.generic.my-agent.event_map.idle]
+# badge = "Stop"
+# badge_color = "green"
+
+# Alternative to event_field/event_map: an ordered rule list, tried top-to-bottom,
+# matching on any field path (not just one) with its own icon/body path per rule
+# [[notification.agents.generic.my-agent.rules]]
+# field = "status.type"
+# equals = "idle"
+# badge = "Stop"
+# badge_color = "green"
+# icon = "agentoast"
+# body_field = "message"
+
+# Status-transition notifications: fire a configured alert (and optional sound)
+# the moment an agent's detected status changes, instead of polling the panel.
+[notification.status_rules]
+# enabled = false
+
+# How often (in seconds) panes are re-polled to detect a status transition
+# poll_interval_secs = 3
+
+# Ping when an agent stops running and needs input (dunst-style "critical" urgency)
+# [[notification.status_rules.rules]]
+# transition = "running_to_waiting"
+# urgency = "critical"
+# summary = "{repo_name}: {agent_type} needs input"
+# sound = "afplay /System/Library/Sounds/Ping.aiff"
+
+# Only notify plan-mode Claude Code sessions when they finish ("normal" urgency)
+# [[notification.status_rules.rules]]
+# transition = "running_to_idle"
+# agent_type = "claude-code"
+# agent_mode = "plan"
+# urgency = "normal"
+# summary = "{repo_name}: {agent_type} finished ({branch})"
+
+# Global keybindings
 # Format: modifier+key (modifiers: ctrl, shift, alt/option, super/cmd)
-# Set to "" to disable
+# Set any entry to "" to disable it. An invalid or conflicting entry is
+# skipped with a warning, without affecting the others.
+[keybinding]
+# Toggle the notification panel (default: super+ctrl+n)
 # toggle_panel = "super+ctrl+n"
 
+# Toggle global mute (default: disabled)
+# toggle_mute = "super+ctrl+m"
+
+# Delete all notifications (default: disabled)
+# clear_all = ""
+
+# Focus the terminal of the most recent notification (default: disabled)
+# focus_latest = ""
+
+# Toggle "show only groups with notifications" (default: disabled)
+# toggle_filter_notified_only = ""
+
+# Focus the terminal of the front-most monitored pane (default: disabled)
+# focus_terminal = ""
+
+# Dismiss the active toast popup (default: disabled)
+# dismiss_toast = ""
+
+# Open config.toml in $EDITOR (default: disabled)
+# open_config = ""
+
+[updater]
+# Release feed polled for update metadata (see tauri-plugin-updater's static
+# JSON format).
+# feed_url = "https://github.com/shuntaka9576/agentoast/releases/latest/download/latest.json"
+
+# How often to check for updates in the background, in seconds.
+# Set to 0 to disable the periodic check; "Check for Updates…" in the tray
+# menu always works regardless of this setting.
+# auto_check_interval_secs = 21600
+
+# Teach the tmux pane scanner about agents beyond the built-in Claude Code/
+# Codex/OpenCode support, or patch detection for a new TUI build without
+# waiting for a release. All three ship as built-in entries under this same
+# key — define e.g. [agent_detection.custom.codex] here to override one.
+# [agent_detection.custom.aider]
+# process_names = ["aider"]
+# running_patterns = ["Thinking..."]
+# waiting_patterns = [{ pattern = "Apply edits?", waiting_reason = "confirmation" }]
+# prompt_prefixes = [">"]
+# footer_patterns = ["for shortcuts"]
+# mode_patterns = [["architect mode", "architect"]]
+# regex = false
+# Regex matching the cursor on the selected option of a numbered dialog
+# (e.g. a `>` before the highlighted choice); always Waiting when it matches.
+# selection_cursor_regex = "^> \\d+\\."
+# Leading glyphs that mark a spinner line, and the running substrings only
+# checked on such a line (see the built-in claude-code entry for an example).
+# spinner_chars = ["*"]
+# spinner_running_patterns = ["Thinking"]
+# Suffixes (checked alongside prompt_prefixes) that mark a shell-style prompt.
+# prompt_suffixes = ["$ ", "%", ">"]
+# Extra skip-line predicates beyond footer_patterns' substring match.
+# skip_rules = [{ kind = "is_separator" }, { kind = "starts_with", pattern = "#" }]
+# Require the spinner/selection cursor lines above to also carry these SGR
+# codes (captures preserve color via `tmux capture-pane -e`); leave unset to
+# match on text alone regardless of color.
+# spinner_required_sgr = ["33"]
+# selection_requires_highlight = true
+
+# Loopback HTTP API (`agentoast admin`) for reading and clearing notifications
+# from other tooling without opening the SQLite file directly. Off by default.
+[admin]
+# enabled = false
+# port = 4318
+# Required in the `Authorization: Bearer <token>` header; unset means no auth
+# (fine on a single-user machine since the listener only binds to 127.0.0.1)
+# token = "replace-with-a-secret"
+
+# Smooth out single-frame misreads before a pane's status changes: a
+# transition into Waiting/Idle only sticks once `m` of the last `k` raw
+# detections agree (a spinner hit still asserts Running immediately).
+# [agent_detection.hysteresis]
+# k = 3
+# m = 2
+
 "#
 }
 
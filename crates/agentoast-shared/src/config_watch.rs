@@ -0,0 +1,111 @@
+//! Live-reloads `config.toml` so in-app toggles (`save_notification_muted`,
+//! `save_notification_filter_notified_only`, ...) and external edits to the
+//! file both take effect without a restart, instead of every `load_config`
+//! caller only ever seeing the config as of process start. Mirrors the
+//! trailing-edge debounce the DB file watcher (`watcher::start`) uses: watch
+//! the parent directory rather than the file itself, since an editor's
+//! atomic save replaces the file instead of writing it in place.
+
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::config::{self, AppConfig};
+
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Watches `path` and sends a freshly-parsed `AppConfig` once per debounce
+/// window after its content changes. A parse failure is logged the same way
+/// `load_config` logs one; the last-good config simply isn't replaced, so
+/// subscribers keep what they already have instead of being pushed a
+/// half-written file.
+pub fn watch_config(path: PathBuf) -> Receiver<AppConfig> {
+    let (out_tx, out_rx) = mpsc::channel();
+
+    std::thread::spawn(move || {
+        let Some(parent) = path.parent().map(|p| p.to_path_buf()) else {
+            log::error!("config watcher: {:?} has no parent directory", path);
+            return;
+        };
+        let file_name = path.file_name().map(|n| n.to_os_string());
+
+        let (fs_tx, fs_rx) = mpsc::channel();
+        let mut watcher: RecommendedWatcher = match Watcher::new(fs_tx, notify::Config::default())
+        {
+            Ok(w) => w,
+            Err(e) => {
+                log::error!("Failed to create config watcher: {}", e);
+                return;
+            }
+        };
+        if let Err(e) = watcher.watch(&parent, RecursiveMode::NonRecursive) {
+            log::error!("Failed to watch config directory: {}", e);
+            return;
+        }
+
+        let mut last_good = config::load_config();
+        let mut last_event: Option<Instant> = None;
+
+        loop {
+            let timeout = match last_event {
+                Some(t) => DEBOUNCE.saturating_sub(t.elapsed()),
+                None => Duration::from_secs(3600),
+            };
+
+            match fs_rx.recv_timeout(timeout) {
+                Ok(Ok(event)) => {
+                    let is_config_event = file_name
+                        .as_deref()
+                        .map(|name| event.paths.iter().any(|p| p.file_name() == Some(name)))
+                        .unwrap_or(false);
+                    if is_config_event
+                        && matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_))
+                    {
+                        last_event = Some(Instant::now());
+                    }
+                }
+                Ok(Err(e)) => log::error!("Config watch error: {}", e),
+                Err(RecvTimeoutError::Timeout) => {
+                    if last_event.take().is_some() {
+                        if let Some(cfg) = reload(&path, &mut last_good) {
+                            if out_tx.send(cfg).is_err() {
+                                return;
+                            }
+                        }
+                    }
+                }
+                Err(RecvTimeoutError::Disconnected) => return,
+            }
+        }
+    });
+
+    out_rx
+}
+
+/// Convenience wrapper over [`watch_config`] for the default `config.toml`
+/// location, mirroring `load_config`'s use of `config::config_path()`.
+pub fn watch_default_config() -> Receiver<AppConfig> {
+    watch_config(config::config_path())
+}
+
+/// Re-parses `path`, returning the new config on success and updating
+/// `last_good` to match. Returns `None` on a parse error, leaving
+/// `last_good` (and thus what subscribers see next) untouched.
+fn reload(path: &Path, last_good: &mut AppConfig) -> Option<AppConfig> {
+    let content = std::fs::read_to_string(path).ok()?;
+    match toml::from_str::<AppConfig>(&content) {
+        Ok(cfg) => {
+            *last_good = cfg.clone();
+            Some(cfg)
+        }
+        Err(e) => {
+            log::warn!(
+                "Failed to parse config.toml: {}, keeping previous config",
+                e
+            );
+            None
+        }
+    }
+}
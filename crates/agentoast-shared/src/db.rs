@@ -1,10 +1,12 @@
 pub use rusqlite::Connection;
 
 use rusqlite::params;
+use rusqlite::OptionalExtension;
 use std::collections::HashMap;
 use std::path::Path;
+use std::time::Duration;
 
-use crate::models::{IconType, Notification};
+use crate::models::{DedupMode, IconType, Notification};
 use crate::schema;
 
 pub fn open(db_path: &Path) -> rusqlite::Result<Connection> {
@@ -21,11 +23,15 @@ pub fn open(db_path: &Path) -> rusqlite::Result<Connection> {
 
 /// Read-only connection without schema initialization.
 /// Use this for long-lived reader threads (watcher, polling) to avoid
-/// repeated CREATE TABLE / migration checks on every query.
+/// repeated CREATE TABLE / migration checks on every query. Still asserts
+/// the on-disk schema version matches what this build's `schema::MIGRATIONS`
+/// expects, since an un-migrated or differently-migrated DB would otherwise
+/// be read with stale column assumptions.
 pub fn open_reader(db_path: &Path) -> rusqlite::Result<Connection> {
     let conn = Connection::open(db_path)?;
     conn.pragma_update(None, "journal_mode", "WAL")?;
     conn.pragma_update(None, "busy_timeout", 5000)?;
+    schema::assert_current_version(&conn)?;
     Ok(conn)
 }
 
@@ -42,6 +48,63 @@ pub fn insert_notification(
     terminal_bundle_id: &str,
     force_focus: bool,
 ) -> rusqlite::Result<i64> {
+    insert_notification_deduped(
+        conn,
+        badge,
+        body,
+        badge_color,
+        icon,
+        metadata,
+        repo,
+        tmux_pane,
+        terminal_bundle_id,
+        force_focus,
+        None,
+        DedupMode::Skip,
+    )
+}
+
+/// Same as [`insert_notification`], but when an identical
+/// `(icon, repo, badge, body, tmux_pane)` notification was already recorded
+/// within `dedup_window_secs` seconds, applies `dedup_mode` instead of
+/// inserting a new row: [`DedupMode::Skip`] returns the existing row's id
+/// unchanged, [`DedupMode::Coalesce`] bumps its `coalesce_count` and refreshes
+/// its timestamp. Pass `dedup_window_secs: None` to disable deduping entirely.
+/// `force_focus` always bypasses deduping and inserts a fresh row, since a
+/// notification that must steal focus can't be silently folded into an
+/// earlier, possibly-already-dismissed one.
+#[allow(clippy::too_many_arguments)]
+pub fn insert_notification_deduped(
+    conn: &Connection,
+    badge: &str,
+    body: &str,
+    badge_color: &str,
+    icon: &IconType,
+    metadata: &HashMap<String, String>,
+    repo: &str,
+    tmux_pane: &str,
+    terminal_bundle_id: &str,
+    force_focus: bool,
+    dedup_window_secs: Option<u64>,
+    dedup_mode: DedupMode,
+) -> rusqlite::Result<i64> {
+    if let Some(window_secs) = dedup_window_secs {
+        let duplicate = if force_focus {
+            None
+        } else {
+            find_recent_duplicate(conn, icon, repo, badge, body, tmux_pane, window_secs)?
+        };
+        if let Some(id) = duplicate {
+            return match dedup_mode {
+                DedupMode::Skip => Ok(id),
+                DedupMode::Coalesce => {
+                    coalesce_notification(conn, id)?;
+                    Ok(id)
+                }
+            };
+        }
+    }
+
     let metadata_json = serde_json::to_string(metadata).unwrap_or_else(|_| "{}".to_string());
 
     // Wrap DELETE+INSERT in a transaction so they produce a single WAL write,
@@ -57,7 +120,7 @@ pub fn insert_notification(
     }
 
     tx.execute(
-        "INSERT INTO notifications (badge, body, badge_color, icon, metadata, repo, tmux_pane, terminal_bundle_id, force_focus)
+        "INSERT INTO notifications (title, body, color, icon, metadata, group_name, tmux_pane, terminal_bundle_id, force_focus)
          VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
         params![badge, body, badge_color, icon.as_str(), metadata_json, repo, tmux_pane, terminal_bundle_id, force_focus as i32],
     )?;
@@ -67,31 +130,79 @@ pub fn insert_notification(
     Ok(id)
 }
 
+/// Look up the most recent notification matching
+/// `(icon, repo, badge, body, tmux_pane)` and return its id if it was created
+/// within the last `window_secs` seconds.
+#[allow(clippy::too_many_arguments)]
+fn find_recent_duplicate(
+    conn: &Connection,
+    icon: &IconType,
+    repo: &str,
+    badge: &str,
+    body: &str,
+    tmux_pane: &str,
+    window_secs: u64,
+) -> rusqlite::Result<Option<i64>> {
+    conn.query_row(
+        "SELECT id FROM notifications
+         WHERE icon = ?1 AND group_name = ?2 AND title = ?3 AND body = ?4 AND tmux_pane = ?5
+           AND created_at >= strftime('%Y-%m-%dT%H:%M:%fZ', 'now', ?6)
+         ORDER BY created_at DESC LIMIT 1",
+        params![
+            icon.as_str(),
+            repo,
+            badge,
+            body,
+            tmux_pane,
+            format!("-{} seconds", window_secs)
+        ],
+        |row| row.get(0),
+    )
+    .optional()
+}
+
+/// Bumps `coalesce_count` on an existing row and refreshes its `created_at`,
+/// used by [`DedupMode::Coalesce`] instead of inserting a duplicate row.
+fn coalesce_notification(conn: &Connection, id: i64) -> rusqlite::Result<()> {
+    conn.execute(
+        "UPDATE notifications
+         SET coalesce_count = coalesce_count + 1,
+             created_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now'),
+             is_read = 0
+         WHERE id = ?1",
+        params![id],
+    )?;
+    Ok(())
+}
+
 fn row_to_notification(row: &rusqlite::Row) -> rusqlite::Result<Notification> {
     let metadata_str: String = row.get(5)?;
     let metadata: HashMap<String, String> = serde_json::from_str(&metadata_str).unwrap_or_default();
 
     Ok(Notification {
         id: row.get(0)?,
-        badge: row.get(1)?,
+        title: row.get(1)?,
         body: row.get(2)?,
-        badge_color: row.get(3)?,
+        color: row.get(3)?,
         icon: row.get(4)?,
         metadata,
-        repo: row.get(6)?,
+        group_name: row.get(6)?,
         tmux_pane: row.get(7)?,
         terminal_bundle_id: row.get(8)?,
         force_focus: row.get::<_, i32>(9)? != 0,
         is_read: row.get::<_, i32>(10)? != 0,
         created_at: row.get(11)?,
+        coalesce_count: row.get(12)?,
+        remind_at: row.get(13)?,
     })
 }
 
+const NOTIFICATION_COLUMNS: &str = "id, title, body, color, icon, metadata, group_name, tmux_pane, terminal_bundle_id, force_focus, is_read, created_at, coalesce_count, remind_at";
+
 pub fn get_notifications(conn: &Connection, limit: i64) -> rusqlite::Result<Vec<Notification>> {
-    let mut stmt = conn.prepare(
-        "SELECT id, badge, body, badge_color, icon, metadata, repo, tmux_pane, terminal_bundle_id, force_focus, is_read, created_at
-         FROM notifications ORDER BY created_at DESC LIMIT ?1",
-    )?;
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {NOTIFICATION_COLUMNS} FROM notifications ORDER BY created_at DESC LIMIT ?1",
+    ))?;
     let rows = stmt.query_map(params![limit], row_to_notification)?;
     rows.collect()
 }
@@ -109,6 +220,64 @@ pub fn delete_notification(conn: &Connection, id: i64) -> rusqlite::Result<()> {
     Ok(())
 }
 
+pub fn mark_read(conn: &Connection, id: i64) -> rusqlite::Result<()> {
+    conn.execute(
+        "UPDATE notifications SET is_read = 1 WHERE id = ?1",
+        params![id],
+    )?;
+    Ok(())
+}
+
+pub fn mark_unread(conn: &Connection, id: i64) -> rusqlite::Result<()> {
+    conn.execute(
+        "UPDATE notifications SET is_read = 0 WHERE id = ?1",
+        params![id],
+    )?;
+    Ok(())
+}
+
+/// Snoozes a notification for `duration`, marking it read in the meantime so
+/// it doesn't sit in the unread count until [`get_due_snoozed`] /
+/// [`clear_snooze`] re-surface it. `remind_at` is computed by SQLite's own
+/// `strftime('now', ...)` rather than formatted on the Rust side, so it stays
+/// in exactly the same clock and format as `created_at`.
+pub fn snooze_notification(
+    conn: &Connection,
+    id: i64,
+    duration: std::time::Duration,
+) -> rusqlite::Result<()> {
+    let modifier = format!("+{} seconds", duration.as_secs());
+    conn.execute(
+        "UPDATE notifications
+         SET remind_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now', ?1), is_read = 1
+         WHERE id = ?2",
+        params![modifier, id],
+    )?;
+    Ok(())
+}
+
+/// Returns every snoozed notification whose `remind_at` has passed `now`
+/// (same `strftime` format as `created_at`), so a background reader can
+/// re-surface them via [`clear_snooze`].
+pub fn get_due_snoozed(conn: &Connection, now: &str) -> rusqlite::Result<Vec<Notification>> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {NOTIFICATION_COLUMNS} FROM notifications
+         WHERE remind_at IS NOT NULL AND remind_at <= ?1
+         ORDER BY remind_at ASC",
+    ))?;
+    let rows = stmt.query_map(params![now], row_to_notification)?;
+    rows.collect()
+}
+
+/// Clears a notification's snooze and marks it unread again, re-surfacing it.
+pub fn clear_snooze(conn: &Connection, id: i64) -> rusqlite::Result<()> {
+    conn.execute(
+        "UPDATE notifications SET remind_at = NULL, is_read = 0 WHERE id = ?1",
+        params![id],
+    )?;
+    Ok(())
+}
+
 pub fn delete_notifications_by_pane(conn: &Connection, tmux_pane: &str) -> rusqlite::Result<usize> {
     conn.execute(
         "DELETE FROM notifications WHERE tmux_pane = ?1",
@@ -147,10 +316,9 @@ pub fn get_latest_notification_by_pane(
     conn: &Connection,
     tmux_pane: &str,
 ) -> rusqlite::Result<Option<Notification>> {
-    let mut stmt = conn.prepare(
-        "SELECT id, badge, body, badge_color, icon, metadata, repo, tmux_pane, terminal_bundle_id, force_focus, is_read, created_at
-         FROM notifications WHERE tmux_pane = ?1 ORDER BY id DESC LIMIT 1",
-    )?;
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {NOTIFICATION_COLUMNS} FROM notifications WHERE tmux_pane = ?1 ORDER BY id DESC LIMIT 1",
+    ))?;
     let mut rows = stmt.query_map(params![tmux_pane], row_to_notification)?;
     match rows.next() {
         Some(Ok(n)) => Ok(Some(n)),
@@ -171,10 +339,156 @@ pub fn get_notifications_after_id(
     conn: &Connection,
     after_id: i64,
 ) -> rusqlite::Result<Vec<Notification>> {
-    let mut stmt = conn.prepare(
-        "SELECT id, badge, body, badge_color, icon, metadata, repo, tmux_pane, terminal_bundle_id, force_focus, is_read, created_at
-         FROM notifications WHERE id > ?1 ORDER BY id ASC",
-    )?;
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {NOTIFICATION_COLUMNS} FROM notifications WHERE id > ?1 ORDER BY id ASC",
+    ))?;
     let rows = stmt.query_map(params![after_id], row_to_notification)?;
     rows.collect()
 }
+
+/// Looks up a cached exemplar embedding by its stable `key` (see
+/// `embedding::classify`). Vectors are stored as a flat little-endian `f32`
+/// blob rather than a second table of floats, since they're never queried
+/// by value — only fetched whole and decoded.
+pub fn get_exemplar_embedding(conn: &Connection, key: &str) -> rusqlite::Result<Option<Vec<f32>>> {
+    let blob: Option<Vec<u8>> = conn
+        .query_row(
+            "SELECT vector FROM status_exemplar_embeddings WHERE key = ?1",
+            params![key],
+            |row| row.get(0),
+        )
+        .optional()?;
+
+    Ok(blob.map(|bytes| {
+        bytes
+            .chunks_exact(4)
+            .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            .collect()
+    }))
+}
+
+pub fn upsert_exemplar_embedding(
+    conn: &Connection,
+    key: &str,
+    vector: &[f32],
+) -> rusqlite::Result<()> {
+    let blob: Vec<u8> = vector.iter().flat_map(|v| v.to_le_bytes()).collect();
+    conn.execute(
+        "INSERT INTO status_exemplar_embeddings (key, vector) VALUES (?1, ?2)
+         ON CONFLICT(key) DO UPDATE SET vector = excluded.vector",
+        params![key, blob],
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inserts_and_reads_back_a_notification() {
+        let dir = tempfile::tempdir().unwrap();
+        let conn = open(&dir.path().join("notifications.db")).unwrap();
+
+        let id = insert_notification(
+            &conn,
+            "Stop",
+            "done",
+            "green",
+            &IconType::Codex,
+            &HashMap::new(),
+            "agentoast",
+            "",
+            "",
+            false,
+        )
+        .unwrap();
+
+        let notifications = get_notifications(&conn, 10).unwrap();
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0].id, id);
+        assert_eq!(notifications[0].title, "Stop");
+        assert_eq!(notifications[0].body, "done");
+        assert_eq!(notifications[0].color, "green");
+        assert_eq!(notifications[0].group_name, "agentoast");
+    }
+}
+
+/// Spawns a background thread that watches `db_path` for newly committed
+/// notifications and returns a channel of each batch, in ascending id order.
+///
+/// Most writers (the `agentoast hook ...` subprocess, `agentoast send`) are a
+/// different OS process than whoever calls `watch`, and SQLite's
+/// `update_hook` only fires for writes made through the connection it's
+/// registered on — so new rows are found by a 500 ms poll loop keyed on
+/// [`get_notifications_after_id`]. The hook is registered on the watcher's
+/// own private connection, which makes no writes, so it does not fire for
+/// writes from any other connection, including other `db::open` connections
+/// in the same process; those rows also arrive via the poll. Callers that used
+/// to watch the database file for `notify` events should use this instead:
+/// it reports the same "something changed" signal without the debounce race
+/// inherent to filesystem events racing a WAL commit.
+pub fn watch(db_path: &Path) -> crossbeam_channel::Receiver<Vec<Notification>> {
+    let (tx, rx) = crossbeam_channel::unbounded();
+    let db_path = db_path.to_path_buf();
+
+    std::thread::Builder::new()
+        .name("agentoast-db-watch".into())
+        .spawn(move || watch_loop(&db_path, tx))
+        .expect("failed to spawn db watch thread");
+
+    rx
+}
+
+fn watch_loop(db_path: &Path, tx: crossbeam_channel::Sender<Vec<Notification>>) {
+    let conn = match open(db_path) {
+        Ok(conn) => conn,
+        Err(e) => {
+            log::error!("db::watch: failed to open {}: {}", db_path.display(), e);
+            return;
+        }
+    };
+
+    let (poke_tx, poke_rx) = crossbeam_channel::unbounded::<()>();
+    conn.update_hook(Some(move |_action, _db_name, table_name: &str, _row_id| {
+        if table_name == "notifications" {
+            let _ = poke_tx.send(());
+        }
+    }));
+
+    let mut last_known_id = get_max_id(&conn).unwrap_or(0);
+    let poll_tick = crossbeam_channel::tick(Duration::from_millis(500));
+
+    loop {
+        let mut select = crossbeam_channel::Select::new();
+        let tick_op = select.recv(&poll_tick);
+        let poke_op = select.recv(&poke_rx);
+
+        let op = select.select();
+        match op.index() {
+            i if i == tick_op => {
+                let _ = op.recv(&poll_tick);
+            }
+            i if i == poke_op => {
+                let _ = op.recv(&poke_rx);
+            }
+            _ => unreachable!("Select only registered two arms"),
+        }
+
+        let new_notifications = match get_notifications_after_id(&conn, last_known_id) {
+            Ok(n) => n,
+            Err(e) => {
+                log::error!("db::watch: failed to query new notifications: {}", e);
+                continue;
+            }
+        };
+
+        if let Some(last) = new_notifications.last() {
+            last_known_id = last.id;
+        }
+
+        if !new_notifications.is_empty() && tx.send(new_notifications).is_err() {
+            return;
+        }
+    }
+}
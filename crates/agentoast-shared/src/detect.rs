@@ -0,0 +1,267 @@
+//! Generic, config-driven pane-content status classification, shared between
+//! the GUI's live tmux scanner (`src-tauri::sessions`) and the CLI's
+//! inspection REPL (`agentoast-cli::repl`) so both read a pane the same way.
+//!
+//! New agent CLIs plug in two ways: most (Codex, Claude Code, OpenCode so
+//! far) are fully expressible as a `CustomAgentConfig` entry (built-in, via
+//! `config::default_custom_agents`, or user-declared under
+//! `[agent_detection.custom.*]`) run through the one `classify_custom_status`
+//! pass below — no Rust needed. An agent whose detection needs logic no
+//! declarative rule can express instead gets an [`AgentDetector`] impl
+//! registered in [`detector_for`]; `ClaudeDetector`/`OpencodeDetector` below
+//! show the (currently trivial) shape of one, each just running its built-in
+//! config through the same shared rule engine.
+
+use crate::ansi::{self, PaneLine};
+use crate::config::{self, CustomAgentConfig};
+use crate::db;
+use crate::models::AgentStatus;
+
+/// Matches `pattern` against `line`, either as a regex or a plain substring
+/// depending on `use_regex`. An invalid regex is treated as a non-match
+/// rather than a panic, so a typo'd config doesn't crash the scanner.
+pub fn matches_pattern(pattern: &str, line: &str, use_regex: bool) -> bool {
+    if use_regex {
+        regex::Regex::new(pattern)
+            .map(|re| re.is_match(line))
+            .unwrap_or(false)
+    } else {
+        line.contains(pattern)
+    }
+}
+
+/// Bottom-up state machine behind a config-declared agent's status
+/// detection — the rule engine every built-in agent (`default_custom_agents`)
+/// and every `[agent_detection.custom.*]` entry is detected through. Scans
+/// pane lines from the last meaningful line upward, classifying each as one
+/// of {prompt, running marker, spinner-gated running marker, waiting marker,
+/// selection cursor, footer/skip, unknown} and stopping at the first line
+/// that settles the decision:
+/// - a running-marker line (always below/at the last prompt, since we reach
+///   it first scanning upward) means Running;
+/// - a waiting-marker or selection-cursor line means Waiting, carrying
+///   whatever `waiting_reason` the rule declares (`"selection"` for the
+///   cursor case);
+/// - a prompt line with no running marker below it means Idle, unless a
+///   pending notification exists for this pane (then Waiting);
+/// - footer lines are skipped without counting against the unknown budget;
+/// - anything else counts as unknown, and hitting `MAX_UNKNOWN_LINES` of
+///   those without reaching a prompt yields Running.
+///
+/// Returns the resolved status plus the `waiting_reason` that produced a
+/// `Waiting` result, if any.
+///
+/// Takes parsed [`PaneLine`]s rather than plain strings so color-gated rules
+/// (`cfg.spinner_required_sgr`, `cfg.selection_requires_highlight`) can check
+/// a line's active SGR codes alongside its text; a line with no escape
+/// sequences (a fixture, or a pane captured without `-e`) simply never
+/// satisfies those checks, which is the plain-text fallback for agents that
+/// don't declare them.
+pub fn classify_custom_status(
+    db_conn: &Option<db::Connection>,
+    pane_id: &str,
+    all_lines: &[PaneLine],
+    cfg: &CustomAgentConfig,
+) -> (AgentStatus, Option<String>) {
+    const MAX_UNKNOWN_LINES: usize = 3;
+    let mut unknown_count = 0;
+
+    for line in all_lines.iter().rev() {
+        let trimmed = line.plain.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        if cfg
+            .footer_patterns
+            .iter()
+            .any(|f| trimmed.contains(f.as_str()))
+        {
+            continue;
+        }
+        if cfg.skip_rules.iter().any(|r| r.matches(trimmed)) {
+            continue;
+        }
+        if cfg
+            .running_patterns
+            .iter()
+            .any(|p| matches_pattern(p, trimmed, cfg.regex))
+        {
+            return (AgentStatus::Running, None);
+        }
+        if !cfg.spinner_chars.is_empty()
+            && trimmed.chars().next().is_some_and(|c| cfg.spinner_chars.contains(&c))
+            && cfg
+                .spinner_running_patterns
+                .iter()
+                .any(|p| matches_pattern(p, trimmed, cfg.regex))
+            && (cfg.spinner_required_sgr.is_empty()
+                || cfg.spinner_required_sgr.iter().any(|code| line.has_sgr(code)))
+        {
+            return (AgentStatus::Running, None);
+        }
+        if let Some(rule) = cfg
+            .waiting_patterns
+            .iter()
+            .find(|w| matches_pattern(&w.pattern, trimmed, cfg.regex))
+        {
+            return (AgentStatus::Waiting, rule.waiting_reason.clone());
+        }
+        if cfg
+            .selection_cursor_regex
+            .as_deref()
+            .is_some_and(|re| matches_pattern(re, trimmed, true))
+            && (!cfg.selection_requires_highlight || line.has_sgr("7"))
+        {
+            return (AgentStatus::Waiting, Some("selection".to_string()));
+        }
+
+        // `prompt_prefixes`/`prompt_suffixes` (when either is declared)
+        // supersede `prompt_patterns`, checked against the line with any
+        // `│ ... │` box border stripped off.
+        let stripped = strip_box_border(trimmed);
+        let is_prompt = if !cfg.prompt_prefixes.is_empty() || !cfg.prompt_suffixes.is_empty() {
+            cfg.prompt_prefixes.iter().any(|p| stripped.starts_with(p.as_str()))
+                || cfg
+                    .prompt_suffixes
+                    .iter()
+                    .any(|s| stripped.ends_with(s.as_str()) || stripped == s.trim_end())
+        } else {
+            cfg.prompt_patterns
+                .iter()
+                .any(|p| matches_pattern(p, stripped, cfg.regex))
+        };
+        if is_prompt {
+            return if let Some(conn) = db_conn {
+                match db::get_latest_notification_by_pane(conn, pane_id) {
+                    Ok(Some(_)) => (AgentStatus::Waiting, None),
+                    _ => (AgentStatus::Idle, None),
+                }
+            } else {
+                (AgentStatus::Idle, None)
+            };
+        }
+
+        unknown_count += 1;
+        if unknown_count >= MAX_UNKNOWN_LINES {
+            return (AgentStatus::Running, None);
+        }
+    }
+
+    (AgentStatus::Running, None)
+}
+
+/// Strip leading/trailing box-drawing vertical bar (│ U+2502) and whitespace,
+/// so a prompt rendered inside a bordered input box (e.g. Claude Code's) is
+/// still recognized. Exposed alongside [`matches_pattern`] as a building
+/// block for any detection code that needs it outside `classify_custom_status`
+/// itself.
+pub fn strip_box_border(line: &str) -> &str {
+    line.trim_start_matches('\u{2502}')
+        .trim_start()
+        .trim_end_matches('\u{2502}')
+        .trim_end()
+}
+
+/// Collects the label text of every numbered-option line still visible in
+/// the pane (e.g. "1. Yes", "2) No") — the same shape Claude Code's
+/// `SkipRule::Regex(r"^\d+\. ")` deliberately excludes from status lines,
+/// surfaced here instead of discarded for callers that want to know what's
+/// on offer during a selection prompt, not just that one is pending.
+pub fn extract_numbered_options(lines: &[PaneLine]) -> Vec<String> {
+    lines
+        .iter()
+        .filter_map(|line| {
+            let trimmed = strip_box_border(line.plain.trim());
+            let rest = trimmed.trim_start_matches(|c: char| c.is_ascii_digit());
+            if rest.len() == trimmed.len() {
+                return None; // no leading digits
+            }
+            let rest = rest.strip_prefix('.').or_else(|| rest.strip_prefix(')'))?;
+            let label = rest.trim();
+            (!label.is_empty()).then(|| label.to_string())
+        })
+        .collect()
+}
+
+/// Implemented by a detector for one agent CLI's pane content. `content` is
+/// the pane's raw capture (ANSI/SGR preserved, see [`ansi::parse_lines`]);
+/// `detect` resolves it to a status, the `waiting_reason` carried by a
+/// `Waiting` result (if any), and any numbered option labels found on a
+/// selection prompt (empty outside one). Register a new agent by adding an
+/// impl and wiring it into [`detector_for`], instead of writing a new
+/// `detect_*_status`/`check_*_pane_content` pair from scratch.
+pub trait AgentDetector {
+    fn detect(
+        &self,
+        content: &str,
+        db_conn: &Option<db::Connection>,
+        pane_id: &str,
+    ) -> (AgentStatus, Option<String>, Vec<String>);
+}
+
+/// Shared building block behind every [`AgentDetector`] impl below: parses
+/// `content` into [`PaneLine`]s, runs it through [`classify_custom_status`]
+/// against `cfg`, and pulls out any numbered options via
+/// [`extract_numbered_options`].
+fn detect_via_custom_rules(
+    content: &str,
+    db_conn: &Option<db::Connection>,
+    pane_id: &str,
+    cfg: &CustomAgentConfig,
+) -> (AgentStatus, Option<String>, Vec<String>) {
+    let lines = ansi::parse_lines(content);
+    let (status, waiting_reason) = classify_custom_status(db_conn, pane_id, &lines, cfg);
+    let options = extract_numbered_options(&lines);
+    (status, waiting_reason, options)
+}
+
+/// Detects Claude Code panes via the built-in `"claude-code"` entry from
+/// [`config::default_custom_agents`], run through the shared rule engine.
+pub struct ClaudeDetector;
+
+impl AgentDetector for ClaudeDetector {
+    fn detect(
+        &self,
+        content: &str,
+        db_conn: &Option<db::Connection>,
+        pane_id: &str,
+    ) -> (AgentStatus, Option<String>, Vec<String>) {
+        let agents = config::default_custom_agents();
+        let cfg = agents
+            .get("claude-code")
+            .expect("default_custom_agents always ships a claude-code entry");
+        detect_via_custom_rules(content, db_conn, pane_id, cfg)
+    }
+}
+
+/// Detects OpenCode panes via the built-in `"opencode"` entry from
+/// [`config::default_custom_agents`], run through the shared rule engine.
+pub struct OpencodeDetector;
+
+impl AgentDetector for OpencodeDetector {
+    fn detect(
+        &self,
+        content: &str,
+        db_conn: &Option<db::Connection>,
+        pane_id: &str,
+    ) -> (AgentStatus, Option<String>, Vec<String>) {
+        let agents = config::default_custom_agents();
+        let cfg = agents
+            .get("opencode")
+            .expect("default_custom_agents always ships an opencode entry");
+        detect_via_custom_rules(content, db_conn, pane_id, cfg)
+    }
+}
+
+/// Selects an [`AgentDetector`] by the program name recorded for a pane
+/// (see `TmuxPane::agent_type`), so a new agent registers here instead of
+/// the caller branching on a hardcoded set of names. Returns `None` for an
+/// agent with no dedicated detector, which callers fall back to
+/// `classify_custom_status` plus a `CustomAgentConfig` for, same as today.
+pub fn detector_for(agent_type: &str) -> Option<Box<dyn AgentDetector>> {
+    match agent_type {
+        "claude-code" => Some(Box::new(ClaudeDetector)),
+        "opencode" => Some(Box::new(OpencodeDetector)),
+        _ => None,
+    }
+}
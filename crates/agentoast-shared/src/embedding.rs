@@ -0,0 +1,180 @@
+//! Pluggable embedding-based fallback for agent-status classification.
+//!
+//! `detect::classify_custom_status` (and the `CustomAgentConfig` rule table
+//! behind it, see that module's doc comment) already covers every agent
+//! declared in `[agent_detection.custom.*]`. The one gap that leaves open is
+//! a pane whose `agent_type` matches no declared agent at all —
+//! `sessions::detect_agent_status` used to just default that case to
+//! `AgentStatus::Running`. This module gives that default a real signal
+//! instead: embed the pane's recent lines, compare them against a small
+//! table of labeled exemplar snippets by cosine similarity, and only trust
+//! the match above [`MATCH_THRESHOLD`]. Anything lower still falls back to
+//! `Running` exactly as before, so recognized agents never regress.
+
+use ndarray::Array1;
+use ordered_float::NotNan;
+
+use crate::db;
+use crate::models::AgentStatus;
+
+/// Anything that can turn text into a fixed-length vector. The default,
+/// [`HashingEmbeddingProvider`], needs no network access or model download;
+/// swap in a real model-backed provider later without touching the
+/// classification logic below.
+pub trait EmbeddingProvider {
+    fn dims(&self) -> usize;
+    fn embed(&self, text: &str) -> Array1<f32>;
+}
+
+/// Hashing-trick bag-of-words embedding: each whitespace token is hashed
+/// into one of `dims` buckets and accumulated, then L2-normalized. Crude
+/// compared to a learned model, but deterministic, offline, and good enough
+/// to separate "waiting for approval" phrasing from "task complete".
+pub struct HashingEmbeddingProvider {
+    dims: usize,
+}
+
+impl HashingEmbeddingProvider {
+    pub fn new(dims: usize) -> Self {
+        Self { dims }
+    }
+}
+
+impl Default for HashingEmbeddingProvider {
+    fn default() -> Self {
+        Self::new(256)
+    }
+}
+
+impl EmbeddingProvider for HashingEmbeddingProvider {
+    fn dims(&self) -> usize {
+        self.dims
+    }
+
+    fn embed(&self, text: &str) -> Array1<f32> {
+        let mut vector = Array1::<f32>::zeros(self.dims);
+        for token in text.to_lowercase().split_whitespace() {
+            let bucket = (fnv1a(token) as usize) % self.dims;
+            vector[bucket] += 1.0;
+        }
+        l2_normalize(vector)
+    }
+}
+
+fn fnv1a(token: &str) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in token.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+fn l2_normalize(mut vector: Array1<f32>) -> Array1<f32> {
+    let norm = vector.dot(&vector).sqrt();
+    if norm > f32::EPSILON {
+        vector.mapv_inplace(|v| v / norm);
+    }
+    vector
+}
+
+/// One labeled example backing the fallback classifier. `key` is the stable
+/// identity used to cache its embedding in SQLite across restarts, since
+/// re-embedding the fixed exemplar table on every poll would be wasted work.
+struct StatusExemplar {
+    key: &'static str,
+    text: &'static str,
+    status: AgentStatus,
+}
+
+const EXEMPLARS: &[StatusExemplar] = &[
+    StatusExemplar {
+        key: "waiting_approval",
+        text: "waiting for your approval",
+        status: AgentStatus::Waiting,
+    },
+    StatusExemplar {
+        key: "waiting_numbered_prompt",
+        text: "do you want to proceed? 1. yes 2. no",
+        status: AgentStatus::Waiting,
+    },
+    StatusExemplar {
+        key: "waiting_confirm",
+        text: "press enter to confirm or esc to cancel",
+        status: AgentStatus::Waiting,
+    },
+    StatusExemplar {
+        key: "running_spinner",
+        text: "running... working on it",
+        status: AgentStatus::Running,
+    },
+    StatusExemplar {
+        key: "running_thinking",
+        text: "thinking, please wait",
+        status: AgentStatus::Running,
+    },
+    StatusExemplar {
+        key: "idle_complete",
+        text: "task complete",
+        status: AgentStatus::Idle,
+    },
+    StatusExemplar {
+        key: "idle_prompt",
+        text: "ready for your next instruction",
+        status: AgentStatus::Idle,
+    },
+];
+
+/// Cosine-similarity threshold an exemplar match must clear before we trust
+/// it over the existing `Running` default. Chosen conservatively: a
+/// below-threshold pane just falls back to the old behavior, so raising this
+/// only ever makes the fallback *more* cautious, never less.
+const MATCH_THRESHOLD: f32 = 0.6;
+
+fn exemplar_vector(
+    conn: Option<&db::Connection>,
+    provider: &dyn EmbeddingProvider,
+    exemplar: &StatusExemplar,
+) -> Array1<f32> {
+    if let Some(conn) = conn {
+        if let Ok(Some(cached)) = db::get_exemplar_embedding(conn, exemplar.key) {
+            if cached.len() == provider.dims() {
+                return Array1::from_vec(cached);
+            }
+        }
+    }
+
+    let vector = provider.embed(exemplar.text);
+    if let Some(conn) = conn {
+        if let Some(slice) = vector.as_slice() {
+            let _ = db::upsert_exemplar_embedding(conn, exemplar.key, slice);
+        }
+    }
+    vector
+}
+
+/// Classifies a pane's recent output against the exemplar table. Returns
+/// `None` when the best match scores below [`MATCH_THRESHOLD`], signaling
+/// "no confident opinion" so the caller can fall back to its own default.
+pub fn classify(
+    conn: Option<&db::Connection>,
+    provider: &dyn EmbeddingProvider,
+    recent_lines: &[String],
+) -> Option<AgentStatus> {
+    if recent_lines.is_empty() {
+        return None;
+    }
+
+    let joined = recent_lines.join("\n");
+    let query = provider.embed(&joined);
+
+    EXEMPLARS
+        .iter()
+        .map(|exemplar| {
+            let vector = exemplar_vector(conn, provider, exemplar);
+            let score = NotNan::new(query.dot(&vector)).unwrap_or(NotNan::new(0.0).unwrap());
+            (score, exemplar.status)
+        })
+        .max_by_key(|(score, _)| *score)
+        .and_then(|(score, status)| (score.into_inner() >= MATCH_THRESHOLD).then_some(status))
+}
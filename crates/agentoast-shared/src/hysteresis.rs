@@ -0,0 +1,117 @@
+//! Per-pane hysteresis smoothing over raw `detect::classify_custom_status`
+//! results, so a single stale/misread capture doesn't flap a pane's reported
+//! status. See [`crate::config::HysteresisConfig`] for the `k`/`m` knobs.
+
+use std::collections::VecDeque;
+
+use crate::config::HysteresisConfig;
+use crate::models::AgentStatus;
+
+/// One raw detection result for a pane: the status plus whatever
+/// `agent_modes` came with it (a `waiting_reason` is already folded in here
+/// by the caller, same as everywhere else in the scanner).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawSample {
+    pub status: AgentStatus,
+    pub modes: Vec<String>,
+}
+
+/// Rolling per-pane state: the last `k` raw samples, plus the sample
+/// currently being reported — so a held-over Waiting/Idle result keeps
+/// reporting the metadata of the sample that last confirmed it, rather than
+/// the newest (still-unconfirmed) one.
+#[derive(Debug, Clone, Default)]
+pub struct PaneHistory {
+    recent: VecDeque<RawSample>,
+    emitted: Option<RawSample>,
+}
+
+impl PaneHistory {
+    /// Folds `sample` into this pane's history and returns the sample to
+    /// actually report. `Running` always takes effect immediately — a
+    /// spinner hit is one way `sample` ends up `Running`, but any running
+    /// marker is treated the same way, since a false Running read is rare
+    /// and harmless next to a false Waiting/Idle one. A `Waiting`/`Idle`
+    /// candidate only takes effect once it's the status in at least `m` of
+    /// the last `k` raw samples; the very first sample seen for a pane is
+    /// always trusted immediately, since there's nothing yet to hold over
+    /// from.
+    pub fn resolve(&mut self, sample: RawSample, cfg: &HysteresisConfig) -> RawSample {
+        self.recent.push_back(sample.clone());
+        while self.recent.len() > cfg.k {
+            self.recent.pop_front();
+        }
+
+        let confirmed = sample.status == AgentStatus::Running
+            || self.emitted.is_none()
+            || self
+                .recent
+                .iter()
+                .filter(|s| s.status == sample.status)
+                .count()
+                >= cfg.m;
+
+        let winner = if confirmed {
+            sample
+        } else {
+            self.emitted.clone().expect("checked by the is_none() branch above")
+        };
+        self.emitted = Some(winner.clone());
+        winner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(status: AgentStatus) -> RawSample {
+        RawSample {
+            status,
+            modes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn first_sample_is_always_trusted() {
+        let mut history = PaneHistory::default();
+        let cfg = HysteresisConfig { k: 3, m: 2 };
+        assert_eq!(
+            history.resolve(sample(AgentStatus::Waiting), &cfg).status,
+            AgentStatus::Waiting
+        );
+    }
+
+    #[test]
+    fn single_stale_read_does_not_flap_status() {
+        let mut history = PaneHistory::default();
+        let cfg = HysteresisConfig { k: 3, m: 2 };
+        history.resolve(sample(AgentStatus::Running), &cfg);
+        history.resolve(sample(AgentStatus::Running), &cfg);
+
+        // One-frame misread of Idle shouldn't stick without a second
+        // confirming sample.
+        let held = history.resolve(sample(AgentStatus::Idle), &cfg);
+        assert_eq!(held.status, AgentStatus::Running);
+    }
+
+    #[test]
+    fn confirmed_transition_takes_effect() {
+        let mut history = PaneHistory::default();
+        let cfg = HysteresisConfig { k: 3, m: 2 };
+        history.resolve(sample(AgentStatus::Running), &cfg);
+        history.resolve(sample(AgentStatus::Idle), &cfg);
+        let confirmed = history.resolve(sample(AgentStatus::Idle), &cfg);
+        assert_eq!(confirmed.status, AgentStatus::Idle);
+    }
+
+    #[test]
+    fn running_bypasses_hysteresis_immediately() {
+        let mut history = PaneHistory::default();
+        let cfg = HysteresisConfig { k: 3, m: 2 };
+        history.resolve(sample(AgentStatus::Waiting), &cfg);
+        history.resolve(sample(AgentStatus::Waiting), &cfg);
+        let running = history.resolve(sample(AgentStatus::Running), &cfg);
+        assert_eq!(running.status, AgentStatus::Running);
+    }
+}
@@ -0,0 +1,108 @@
+//! Parses human-friendly interval strings like `"15m"`, `"2h30m"`, or `"1d"`
+//! into a [`Duration`], used by the snooze CLI/config so users don't have to
+//! spell out raw seconds.
+
+use std::time::Duration;
+
+/// Parses a string made of one or more `<number><unit>` pairs (no separators
+/// required between them, e.g. `"2h30m"`) into a single summed [`Duration`].
+/// Units: `s` seconds, `m` minutes, `h` hours, `d` days, `w` weeks. Rejects an
+/// empty string, an unrecognized unit, a non-digit where a number is
+/// expected, and a sum that would overflow `Duration`.
+pub fn parse_interval(s: &str) -> Result<Duration, String> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err("interval string is empty".to_string());
+    }
+
+    let mut total = Duration::ZERO;
+    let mut chars = s.char_indices().peekable();
+
+    while let Some(&(start, c)) = chars.peek() {
+        if !c.is_ascii_digit() {
+            return Err(format!("expected a number at position {start} in {s:?}"));
+        }
+
+        let mut end = start;
+        while let Some(&(i, c)) = chars.peek() {
+            if c.is_ascii_digit() {
+                end = i;
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        let number_str = &s[start..=end];
+        let amount: u64 = number_str
+            .parse()
+            .map_err(|_| format!("{number_str:?} is not a valid number"))?;
+
+        let (unit_pos, unit) = match chars.next() {
+            Some(pair) => pair,
+            None => return Err(format!("missing unit after {number_str:?} in {s:?}")),
+        };
+        let secs_per_unit: u64 = match unit {
+            's' => 1,
+            'm' => 60,
+            'h' => 60 * 60,
+            'd' => 60 * 60 * 24,
+            'w' => 60 * 60 * 24 * 7,
+            other => return Err(format!("unrecognized unit {other:?} at position {unit_pos}")),
+        };
+
+        let secs = amount
+            .checked_mul(secs_per_unit)
+            .ok_or_else(|| format!("{number_str}{unit} overflows a duration"))?;
+        total = total
+            .checked_add(Duration::from_secs(secs))
+            .ok_or_else(|| "summed interval overflows a duration".to_string())?;
+    }
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_single_unit() {
+        assert_eq!(parse_interval("15m").unwrap(), Duration::from_secs(15 * 60));
+    }
+
+    #[test]
+    fn parses_combined_units() {
+        assert_eq!(
+            parse_interval("2h30m").unwrap(),
+            Duration::from_secs(2 * 60 * 60 + 30 * 60)
+        );
+    }
+
+    #[test]
+    fn parses_days_and_weeks() {
+        assert_eq!(parse_interval("1d").unwrap(), Duration::from_secs(60 * 60 * 24));
+        assert_eq!(
+            parse_interval("1w").unwrap(),
+            Duration::from_secs(60 * 60 * 24 * 7)
+        );
+    }
+
+    #[test]
+    fn rejects_empty_input() {
+        assert!(parse_interval("").is_err());
+        assert!(parse_interval("   ").is_err());
+    }
+
+    #[test]
+    fn rejects_garbage() {
+        assert!(parse_interval("banana").is_err());
+        assert!(parse_interval("15").is_err());
+        assert!(parse_interval("m5").is_err());
+        assert!(parse_interval("15x").is_err());
+    }
+
+    #[test]
+    fn rejects_overflow() {
+        assert!(parse_interval("99999999999999999999w").is_err());
+    }
+}
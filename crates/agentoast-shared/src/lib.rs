@@ -0,0 +1,12 @@
+pub mod ansi;
+pub mod capture;
+pub mod config;
+pub mod config_watch;
+pub mod db;
+pub mod detect;
+pub mod embedding;
+pub mod hysteresis;
+pub mod interval;
+pub mod models;
+pub mod notifier;
+mod schema;
@@ -42,6 +42,16 @@ impl std::fmt::Display for IconType {
     }
 }
 
+/// What to do when an equivalent notification arrives again within the dedup
+/// window: drop it entirely, or keep the original row and bump its count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum DedupMode {
+    #[default]
+    Skip,
+    Coalesce,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Notification {
@@ -57,6 +67,13 @@ pub struct Notification {
     pub force_focus: bool,
     pub is_read: bool,
     pub created_at: String,
+    /// Number of equivalent notifications folded into this row by
+    /// `DedupMode::Coalesce` (1 if it was never coalesced).
+    pub coalesce_count: i64,
+    /// When set, this notification is snoozed until this timestamp
+    /// (`strftime('%Y-%m-%dT%H:%M:%fZ', ...)` format, matching `created_at`).
+    /// `db::get_due_snoozed` re-surfaces it once this time passes.
+    pub remind_at: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -67,6 +84,16 @@ pub struct NotificationGroup {
     pub unread_count: i64,
 }
 
+/// Coarse liveness signal for an agent running in a pane, inferred from
+/// spinner/status-bar/prompt heuristics specific to each agent type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AgentStatus {
+    Running,
+    Waiting,
+    Idle,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TmuxPane {
@@ -75,7 +102,21 @@ pub struct TmuxPane {
     pub session_name: String,
     pub window_name: String,
     pub current_path: String,
+    pub is_active: bool,
     pub agent_type: Option<String>,
+    pub agent_status: Option<AgentStatus>,
+    pub agent_modes: Vec<String>,
+    pub git_repo_root: Option<String>,
+    pub git_branch: Option<String>,
+    /// `true` when the pane's repo is on a detached HEAD.
+    pub git_detached: bool,
+    /// `true` when the working tree has changed/renamed/unmerged/untracked entries.
+    pub git_dirty: bool,
+    pub git_ahead: u32,
+    pub git_behind: u32,
+    /// Directory name of the worktree checkout, set when this pane's repo
+    /// path is a linked worktree rather than the main checkout.
+    pub git_worktree_name: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -83,5 +124,10 @@ pub struct TmuxPane {
 pub struct TmuxPaneGroup {
     pub repo_name: String,
     pub current_path: String,
+    pub git_branch: Option<String>,
+    pub git_detached: bool,
+    pub git_dirty: bool,
+    pub git_ahead: u32,
+    pub git_behind: u32,
     pub panes: Vec<TmuxPane>,
 }
@@ -0,0 +1,489 @@
+//! Pluggable delivery backends for notifications.
+//!
+//! `db::insert_notification` is the system of record (the SQLite feed the
+//! Tauri panel polls), but nothing alerts the user outside of that panel.
+//! `Notifier` backends fan a notification out to other channels; a failure in
+//! one backend must never fail the hook, so [`dispatch`] collects per-backend
+//! errors instead of short-circuiting.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use base64::Engine;
+
+use crate::config::{DeliveryConfig, SmtpTlsMode};
+use crate::models::IconType;
+
+/// The data a backend needs to render a notification on its channel.
+pub struct NotificationPayload<'a> {
+    pub badge: &'a str,
+    pub body: &'a str,
+    pub badge_color: &'a str,
+    pub icon: &'a IconType,
+    pub repo_name: &'a str,
+    pub force_focus: bool,
+}
+
+pub trait Notifier {
+    /// Human-readable name used in log lines when delivery fails.
+    fn name(&self) -> &'static str;
+
+    fn deliver(&self, payload: &NotificationPayload) -> Result<(), String>;
+}
+
+/// Writes the notification to the log and otherwise does nothing. Used when
+/// no other backend is enabled, or as a safe default in tests.
+pub struct NoopNotifier;
+
+impl Notifier for NoopNotifier {
+    fn name(&self) -> &'static str {
+        "noop"
+    }
+
+    fn deliver(&self, payload: &NotificationPayload) -> Result<(), String> {
+        log::debug!(
+            "[notifier:noop] {} [{}] {}",
+            payload.badge,
+            payload.repo_name,
+            payload.body
+        );
+        Ok(())
+    }
+}
+
+/// Cross-platform desktop toast, separate from the Tauri panel/toast window
+/// (useful for the headless CLI, or as a fallback when the GUI isn't running).
+pub struct DesktopNotifier;
+
+impl Notifier for DesktopNotifier {
+    fn name(&self) -> &'static str {
+        "desktop"
+    }
+
+    fn deliver(&self, payload: &NotificationPayload) -> Result<(), String> {
+        let summary = if payload.repo_name.is_empty() {
+            payload.badge.to_string()
+        } else {
+            format!("{}: {}", payload.repo_name, payload.badge)
+        };
+
+        notify_rust::Notification::new()
+            .summary(&summary)
+            .body(payload.body)
+            .appname("agentoast")
+            .show()
+            .map(|_| ())
+            .map_err(|e| format!("desktop notification failed: {}", e))
+    }
+}
+
+/// Sends a short subject/body email via SMTP, using credentials from config.
+pub struct EmailNotifier {
+    pub host: String,
+    pub port: u16,
+    pub username: String,
+    pub password: String,
+    pub from: String,
+    pub to: String,
+    pub tls: SmtpTlsMode,
+}
+
+impl Notifier for EmailNotifier {
+    fn name(&self) -> &'static str {
+        "email"
+    }
+
+    fn deliver(&self, payload: &NotificationPayload) -> Result<(), String> {
+        let subject = if payload.repo_name.is_empty() {
+            payload.badge.to_string()
+        } else {
+            format!("[{}] {}", payload.repo_name, payload.badge)
+        };
+
+        let email = lettre::Message::builder()
+            .from(self.from.parse().map_err(|e| format!("invalid from address: {}", e))?)
+            .to(self.to.parse().map_err(|e| format!("invalid to address: {}", e))?)
+            .subject(subject)
+            .body(payload.body.to_string())
+            .map_err(|e| format!("failed to build email: {}", e))?;
+
+        let creds = lettre::transport::smtp::authentication::Credentials::new(
+            self.username.clone(),
+            self.password.clone(),
+        );
+
+        let builder = match self.tls {
+            SmtpTlsMode::Starttls => lettre::SmtpTransport::starttls_relay(&self.host),
+            SmtpTlsMode::Implicit => lettre::SmtpTransport::relay(&self.host),
+        }
+        .map_err(|e| format!("failed to configure SMTP relay: {}", e))?;
+
+        let mailer = builder.port(self.port).credentials(creds).build();
+
+        lettre::Transport::send(&mailer, &email)
+            .map(|_| ())
+            .map_err(|e| format!("failed to send email: {}", e))
+    }
+}
+
+/// Posts a background alert to an iPhone via Apple Push Notification service,
+/// so a notification reaches the developer when they're away from the
+/// terminal. Auth is a bearer JWT (ES256 over the `team_id`/`key_id` header),
+/// which APNs accepts for up to an hour before it needs to be re-signed.
+pub struct ApnsNotifier {
+    pub signing_key_path: String,
+    pub team_id: String,
+    pub key_id: String,
+    pub topic: String,
+    pub device_tokens: Vec<String>,
+    pub sandbox: bool,
+    token_cache: Mutex<Option<(String, Instant)>>,
+}
+
+impl ApnsNotifier {
+    pub fn new(
+        signing_key_path: String,
+        team_id: String,
+        key_id: String,
+        topic: String,
+        device_tokens: Vec<String>,
+        sandbox: bool,
+    ) -> Self {
+        Self {
+            signing_key_path,
+            team_id,
+            key_id,
+            topic,
+            device_tokens,
+            sandbox,
+            token_cache: Mutex::new(None),
+        }
+    }
+
+    fn endpoint(&self) -> &'static str {
+        if self.sandbox {
+            "https://api.sandbox.push.apple.com"
+        } else {
+            "https://api.push.apple.com"
+        }
+    }
+
+    /// Returns a cached bearer token if it was minted less than 55 minutes ago,
+    /// otherwise signs a fresh one with the `.p8` key.
+    fn bearer_token(&self) -> Result<String, String> {
+        let mut cache = self.token_cache.lock().unwrap();
+        if let Some((token, minted_at)) = cache.as_ref() {
+            if minted_at.elapsed() < Duration::from_secs(55 * 60) {
+                return Ok(token.clone());
+            }
+        }
+
+        let token = self.sign_token()?;
+        *cache = Some((token.clone(), Instant::now()));
+        Ok(token)
+    }
+
+    /// Builds and signs the ES256 JWT APNs expects: header carries `kid`
+    /// (key id), claims carry `iss` (team id) and `iat` (issued-at).
+    fn sign_token(&self) -> Result<String, String> {
+        let pem = std::fs::read_to_string(&self.signing_key_path)
+            .map_err(|e| format!("failed to read APNs signing key: {}", e))?;
+
+        let encoding_key = jsonwebtoken::EncodingKey::from_ec_pem(pem.as_bytes())
+            .map_err(|e| format!("invalid APNs signing key: {}", e))?;
+
+        let mut header = jsonwebtoken::Header::new(jsonwebtoken::Algorithm::ES256);
+        header.kid = Some(self.key_id.clone());
+
+        let claims = ApnsClaims {
+            iss: self.team_id.clone(),
+            iat: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+        };
+
+        jsonwebtoken::encode(&header, &claims, &encoding_key)
+            .map_err(|e| format!("failed to sign APNs token: {}", e))
+    }
+
+    fn build_body(&self, payload: &NotificationPayload) -> serde_json::Value {
+        let title = if payload.repo_name.is_empty() {
+            payload.badge.to_string()
+        } else {
+            format!("{}: {}", payload.repo_name, payload.badge)
+        };
+
+        serde_json::json!({
+            "aps": {
+                "alert": {
+                    "title": title,
+                    "body": payload.body,
+                },
+                "thread-id": payload.repo_name,
+                "sound": "default",
+            },
+            "badge_color": payload.badge_color,
+            "icon": payload.icon.as_str(),
+        })
+    }
+}
+
+impl Notifier for ApnsNotifier {
+    fn name(&self) -> &'static str {
+        "apns"
+    }
+
+    fn deliver(&self, payload: &NotificationPayload) -> Result<(), String> {
+        if self.device_tokens.is_empty() {
+            return Err("no APNs device tokens configured".to_string());
+        }
+
+        let bearer = self.bearer_token()?;
+        let body = self.build_body(payload);
+        let priority = if payload.force_focus { "10" } else { "5" };
+
+        let client = reqwest::blocking::Client::new();
+        let mut failures = Vec::new();
+
+        for device_token in &self.device_tokens {
+            let url = format!("{}/3/device/{}", self.endpoint(), device_token);
+            let result = client
+                .post(&url)
+                .version(reqwest::Version::HTTP_2)
+                .bearer_auth(&bearer)
+                .header("apns-topic", &self.topic)
+                .header("apns-priority", priority)
+                .header("apns-push-type", "alert")
+                .json(&body)
+                .send();
+
+            match result {
+                Ok(resp) if resp.status().is_success() => {}
+                Ok(resp) => failures.push(format!(
+                    "device {}…: APNs returned {}",
+                    &device_token[..device_token.len().min(8)],
+                    resp.status()
+                )),
+                Err(e) => failures.push(format!(
+                    "device {}…: {}",
+                    &device_token[..device_token.len().min(8)],
+                    e
+                )),
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(failures.join("; "))
+        }
+    }
+}
+
+#[derive(serde::Serialize)]
+struct ApnsClaims {
+    iss: String,
+    iat: u64,
+}
+
+/// Maximum delivery attempts for [`WebhookNotifier`] before giving up on a
+/// 5xx response or timeout.
+const WEBHOOK_MAX_ATTEMPTS: u32 = 4;
+
+/// Posts the notification as JSON to an arbitrary HTTP endpoint, signed per
+/// the [Standard Webhooks](https://www.standardwebhooks.com) convention:
+/// `webhook-id` (a ULID), `webhook-timestamp` (unix seconds), and
+/// `webhook-signature: v1,{base64(HMAC_SHA256(secret, "{id}.{timestamp}.{body}"))}`.
+/// Retries with exponential backoff on 5xx responses or timeouts.
+pub struct WebhookNotifier {
+    pub url: String,
+    pub secret: Option<String>,
+}
+
+impl WebhookNotifier {
+    fn sign(&self, webhook_id: &str, timestamp: u64, body: &[u8]) -> Result<String, String> {
+        use hmac::{Hmac, Mac};
+        use sha2::Sha256;
+
+        let secret = self
+            .secret
+            .as_deref()
+            .ok_or_else(|| "no webhook secret configured".to_string())?;
+
+        let signed_content = format!(
+            "{}.{}.{}",
+            webhook_id,
+            timestamp,
+            String::from_utf8_lossy(body)
+        );
+
+        let key = base64::engine::general_purpose::STANDARD
+            .decode(secret)
+            .map_err(|e| format!("webhook secret is not valid base64: {}", e))?;
+        let mut mac = Hmac::<Sha256>::new_from_slice(&key)
+            .map_err(|e| format!("invalid webhook secret: {}", e))?;
+        mac.update(signed_content.as_bytes());
+
+        Ok(base64::engine::general_purpose::STANDARD.encode(mac.finalize().into_bytes()))
+    }
+}
+
+impl Notifier for WebhookNotifier {
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+
+    fn deliver(&self, payload: &NotificationPayload) -> Result<(), String> {
+        let body = serde_json::json!({
+            "badge": payload.badge,
+            "body": payload.body,
+            "badge_color": payload.badge_color,
+            "icon": payload.icon.as_str(),
+            "repo_name": payload.repo_name,
+            "force_focus": payload.force_focus,
+        });
+        let body_bytes =
+            serde_json::to_vec(&body).map_err(|e| format!("failed to build webhook body: {}", e))?;
+
+        let webhook_id = ulid::Ulid::new().to_string();
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        let client = reqwest::blocking::Client::new();
+        let mut last_error = String::new();
+        let mut attempts_made = 0;
+
+        for attempt in 1..=WEBHOOK_MAX_ATTEMPTS {
+            attempts_made = attempt;
+            let mut request = client
+                .post(&self.url)
+                .header("Content-Type", "application/json")
+                .header("webhook-id", &webhook_id)
+                .header("webhook-timestamp", timestamp.to_string());
+
+            if self.secret.is_some() {
+                let signature = self.sign(&webhook_id, timestamp, &body_bytes)?;
+                request = request.header("webhook-signature", format!("v1,{}", signature));
+            }
+
+            let result = request.body(body_bytes.clone()).send();
+
+            let should_retry = match &result {
+                Ok(resp) if resp.status().is_success() => return Ok(()),
+                Ok(resp) => {
+                    last_error = format!("webhook endpoint returned {}", resp.status());
+                    resp.status().is_server_error()
+                }
+                Err(e) => {
+                    last_error = format!("webhook request failed: {}", e);
+                    e.is_timeout()
+                }
+            };
+
+            if !should_retry || attempt == WEBHOOK_MAX_ATTEMPTS {
+                break;
+            }
+
+            std::thread::sleep(Duration::from_millis(200 * 2u64.pow(attempt)));
+        }
+
+        Err(format!(
+            "{} (after {} attempt(s))",
+            last_error, attempts_made
+        ))
+    }
+}
+
+/// Builds the set of enabled backends from config, without the `NoopNotifier`
+/// fallback — used both by [`backends_from_config`] (global default set) and
+/// [`backends_for_channels`] (per-agent `channels` filtering).
+fn enabled_backends(config: &DeliveryConfig) -> Vec<Box<dyn Notifier>> {
+    let mut backends: Vec<Box<dyn Notifier>> = Vec::new();
+
+    if config.desktop {
+        backends.push(Box::new(DesktopNotifier));
+    }
+
+    if let Some(email) = &config.email {
+        if email.enabled {
+            backends.push(Box::new(EmailNotifier {
+                host: email.host.clone(),
+                port: email.port,
+                username: email.username.clone(),
+                password: email.password.clone(),
+                from: email.from.clone(),
+                to: email.to.clone(),
+                tls: email.tls,
+            }));
+        }
+    }
+
+    if let Some(apns) = &config.apns {
+        if apns.enabled {
+            backends.push(Box::new(ApnsNotifier::new(
+                apns.signing_key_path.clone(),
+                apns.team_id.clone(),
+                apns.key_id.clone(),
+                apns.topic.clone(),
+                apns.device_tokens.clone(),
+                apns.sandbox,
+            )));
+        }
+    }
+
+    if let Some(webhook) = &config.webhook {
+        if webhook.enabled {
+            backends.push(Box::new(WebhookNotifier {
+                url: webhook.url.clone(),
+                secret: webhook.secret.clone(),
+            }));
+        }
+    }
+
+    backends
+}
+
+/// Builds the set of enabled backends from config.
+pub fn backends_from_config(config: &DeliveryConfig) -> Vec<Box<dyn Notifier>> {
+    let backends = enabled_backends(config);
+    if backends.is_empty() {
+        vec![Box::new(NoopNotifier)]
+    } else {
+        backends
+    }
+}
+
+/// Same as [`backends_from_config`], but restricted to the backends named in
+/// `channels` (an agent's `channels` list in `config.toml`). An empty list
+/// means "use every enabled backend", matching the global default.
+pub fn backends_for_channels(config: &DeliveryConfig, channels: &[String]) -> Vec<Box<dyn Notifier>> {
+    let backends = enabled_backends(config);
+
+    let filtered: Vec<Box<dyn Notifier>> = if channels.is_empty() {
+        backends
+    } else {
+        backends
+            .into_iter()
+            .filter(|backend| channels.iter().any(|c| c == backend.name()))
+            .collect()
+    };
+
+    if filtered.is_empty() {
+        vec![Box::new(NoopNotifier)]
+    } else {
+        filtered
+    }
+}
+
+/// Fan a notification out to every enabled backend, logging (not propagating)
+/// per-backend failures so a broken email/webhook config never fails the hook.
+pub fn dispatch(backends: &[Box<dyn Notifier>], payload: &NotificationPayload) {
+    for backend in backends {
+        if let Err(e) = backend.deliver(payload) {
+            log::warn!("[notifier:{}] delivery failed: {}", backend.name(), e);
+        }
+    }
+}
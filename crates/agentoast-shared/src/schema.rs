@@ -1,29 +1,94 @@
 use rusqlite::Connection;
 
+/// Ordered schema migrations. Each entry is the SQL to reach version `index + 1`
+/// starting from `PRAGMA user_version`. Never edit a migration once released;
+/// append a new one instead so already-migrated databases stay consistent.
+const MIGRATIONS: &[&str] = &[
+    // 0 -> 1: base schema (no DROP, so upgrading an existing install keeps history)
+    "
+    CREATE TABLE IF NOT EXISTS notifications (
+        id            INTEGER PRIMARY KEY AUTOINCREMENT,
+        title         TEXT NOT NULL DEFAULT '',
+        body          TEXT NOT NULL DEFAULT '',
+        color         TEXT NOT NULL DEFAULT 'gray',
+        icon          TEXT NOT NULL DEFAULT 'agentoast',
+        group_name    TEXT NOT NULL DEFAULT '',
+        metadata      TEXT NOT NULL DEFAULT '{}',
+        tmux_pane     TEXT NOT NULL DEFAULT '',
+        terminal_bundle_id TEXT NOT NULL DEFAULT '',
+        force_focus   INTEGER NOT NULL DEFAULT 0,
+        is_read       INTEGER NOT NULL DEFAULT 0,
+        created_at    TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+    );
+
+    CREATE INDEX IF NOT EXISTS idx_notifications_created_at ON notifications(created_at DESC);
+    CREATE INDEX IF NOT EXISTS idx_notifications_group_name ON notifications(group_name);
+    ",
+    // 1 -> 2: coalesce_count tracks how many duplicate notifications a
+    // DedupMode::Coalesce hit has folded into one row (1 if never coalesced).
+    "
+    ALTER TABLE notifications ADD COLUMN coalesce_count INTEGER NOT NULL DEFAULT 1;
+    ",
+    // 2 -> 3: caches embeddings for the fallback status classifier's
+    // exemplar table (`embedding::classify`) so they're computed once per
+    // install rather than re-embedded on every poll.
+    "
+    CREATE TABLE IF NOT EXISTS status_exemplar_embeddings (
+        key    TEXT PRIMARY KEY,
+        vector BLOB NOT NULL
+    );
+    ",
+    // 3 -> 4: remind_at backs the snooze feature (db::snooze_notification /
+    // db::get_due_snoozed / db::clear_snooze) -- NULL means "not snoozed".
+    "
+    ALTER TABLE notifications ADD COLUMN remind_at TEXT;
+    ",
+];
+
+/// Apply any pending migrations, bumping `PRAGMA user_version` as it goes.
+/// Safe to call on every `open()`: once `user_version` reaches `MIGRATIONS.len()`
+/// this is a single read-only pragma check and returns immediately.
 pub fn initialize(conn: &Connection) -> rusqlite::Result<()> {
-    conn.execute_batch(
-        "
-        DROP TABLE IF EXISTS notifications;
-
-        CREATE TABLE notifications (
-            id            INTEGER PRIMARY KEY AUTOINCREMENT,
-            title         TEXT NOT NULL DEFAULT '',
-            body          TEXT NOT NULL DEFAULT '',
-            color         TEXT NOT NULL DEFAULT 'gray',
-            icon          TEXT NOT NULL DEFAULT 'agentoast',
-            group_name    TEXT NOT NULL DEFAULT '',
-            metadata      TEXT NOT NULL DEFAULT '{}',
-            tmux_pane     TEXT NOT NULL DEFAULT '',
-            terminal_bundle_id TEXT NOT NULL DEFAULT '',
-            force_focus   INTEGER NOT NULL DEFAULT 0,
-            is_read       INTEGER NOT NULL DEFAULT 0,
-            created_at    TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
-        );
-
-        CREATE INDEX IF NOT EXISTS idx_notifications_created_at ON notifications(created_at DESC);
-        CREATE INDEX IF NOT EXISTS idx_notifications_group_name ON notifications(group_name);
-        ",
-    )?;
+    let user_version: i64 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+    let target_version = MIGRATIONS.len() as i64;
+
+    if user_version >= target_version {
+        return Ok(());
+    }
+
+    let tx = conn.unchecked_transaction()?;
+
+    for migration in &MIGRATIONS[user_version.max(0) as usize..] {
+        tx.execute_batch(migration)?;
+    }
+
+    tx.execute_batch(&format!("PRAGMA user_version = {}", target_version))?;
+    tx.commit()?;
+
+    Ok(())
+}
+
+/// Checks `conn`'s `PRAGMA user_version` against `MIGRATIONS.len()` without
+/// running anything. `open_reader` uses this instead of `initialize` so
+/// reader threads (watcher, polling) don't pay for a migration check on
+/// every connection -- but a reader built against an older `MIGRATIONS` list
+/// than the `open()` that last migrated the file would otherwise read
+/// columns it doesn't know about (or miss ones it expects), so it still
+/// needs to fail loudly rather than silently misreading rows.
+pub fn assert_current_version(conn: &Connection) -> rusqlite::Result<()> {
+    let user_version: i64 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+    let target_version = MIGRATIONS.len() as i64;
+
+    if user_version != target_version {
+        return Err(rusqlite::Error::SqliteFailure(
+            rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_SCHEMA),
+            Some(format!(
+                "database schema is at version {user_version}, but this build expects version \
+                 {target_version}; run db::open() (e.g. restart the app) to migrate before \
+                 opening a reader connection"
+            )),
+        ));
+    }
 
     Ok(())
 }
@@ -0,0 +1,140 @@
+//! Golden-snapshot regression harness for `detect::classify_custom_status`.
+//! Every `tests/fixtures/<agent_type>/<name>.txt` is a recorded `capture-pane`
+//! dump, paired with a `<name>.toml` sidecar describing the expected
+//! `(status, waiting_reason, modes)`. Drop a failing real-world capture (and
+//! its sidecar) in here instead of hand-writing a one-off `#[test]` to lock
+//! in a detection fix.
+//!
+//! `agent_type` must name a `default_custom_agents()` entry (e.g.
+//! `"claude-code"`, `"codex"`, `"opencode"`); a sidecar's optional
+//! `selection_cursor_regex` overrides that one fixture's config without
+//! touching the real default.
+
+#![cfg(feature = "fixture-capture")]
+
+use std::path::{Path, PathBuf};
+
+use agentoast_shared::ansi::{parse_lines, PaneLine};
+use agentoast_shared::config::{default_custom_agents, CustomAgentConfig};
+use agentoast_shared::detect::{classify_custom_status, matches_pattern};
+use agentoast_shared::models::AgentStatus;
+
+#[derive(serde::Deserialize)]
+struct Expected {
+    status: String,
+    #[serde(default)]
+    waiting_reason: Option<String>,
+    #[serde(default)]
+    modes: Vec<String>,
+    #[serde(default)]
+    selection_cursor_regex: Option<String>,
+}
+
+fn parse_status(name: &str, s: &str) -> AgentStatus {
+    match s {
+        "running" => AgentStatus::Running,
+        "waiting" => AgentStatus::Waiting,
+        "idle" => AgentStatus::Idle,
+        other => panic!("fixture {name}: unknown status {other:?} in sidecar"),
+    }
+}
+
+/// Mirrors `src-tauri::sessions::detect_custom_status`'s mode scan: the last
+/// 30 non-empty lines checked against `cfg.mode_patterns`, independent of the
+/// bottom-up `classify_custom_status` pass below.
+fn scan_modes(lines: &[PaneLine], cfg: &CustomAgentConfig) -> Vec<String> {
+    let mut modes = Vec::new();
+    for line in lines.iter().rev().filter(|l| !l.plain.trim().is_empty()).take(30) {
+        let trimmed = line.plain.trim();
+        for (pattern, label) in &cfg.mode_patterns {
+            if !modes.iter().any(|m| m == label) && matches_pattern(pattern, trimmed, cfg.regex) {
+                modes.push(label.clone());
+            }
+        }
+    }
+    modes
+}
+
+fn fixtures_dir(agent_type: &str) -> PathBuf {
+    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures")).join(agent_type)
+}
+
+/// Runs one `<name>.txt`/`<name>.toml` pair, returning a diff message on
+/// mismatch instead of panicking, so `run_agent_fixtures` can report every
+/// failing fixture in one go rather than stopping at the first.
+fn check_fixture(agent_type: &str, name: &str) -> Result<(), String> {
+    let dir = fixtures_dir(agent_type);
+    let content = std::fs::read_to_string(dir.join(format!("{name}.txt")))
+        .unwrap_or_else(|e| panic!("reading {agent_type}/{name}.txt: {e}"));
+    let sidecar = std::fs::read_to_string(dir.join(format!("{name}.toml")))
+        .unwrap_or_else(|e| panic!("reading {agent_type}/{name}.toml: {e}"));
+    let expected: Expected =
+        toml::from_str(&sidecar).unwrap_or_else(|e| panic!("parsing {agent_type}/{name}.toml: {e}"));
+
+    let mut cfg = default_custom_agents()
+        .remove(agent_type)
+        .unwrap_or_else(|| panic!("no default_custom_agents entry for {agent_type:?}"));
+    if let Some(re) = &expected.selection_cursor_regex {
+        cfg.selection_cursor_regex = Some(re.clone());
+    }
+
+    let lines = parse_lines(&content);
+    let (status, waiting_reason) = classify_custom_status(&None, "fixture-pane", &lines, &cfg);
+    let modes = scan_modes(&lines, &cfg);
+
+    let expected_status = parse_status(name, &expected.status);
+    if status == expected_status && waiting_reason == expected.waiting_reason && modes == expected.modes {
+        return Ok(());
+    }
+
+    Err(format!(
+        "{agent_type}/{name}:\n  expected: status={:?} waiting_reason={:?} modes={:?}\n  actual:   status={:?} waiting_reason={:?} modes={:?}",
+        expected_status, expected.waiting_reason, expected.modes, status, waiting_reason, modes,
+    ))
+}
+
+fn run_agent_fixtures(agent_type: &str) {
+    let dir = fixtures_dir(agent_type);
+    let entries = std::fs::read_dir(&dir).unwrap_or_else(|e| panic!("reading {dir:?}: {e}"));
+
+    let mut failures = Vec::new();
+    let mut checked = 0;
+    for entry in entries {
+        let path = entry.unwrap_or_else(|e| panic!("reading {dir:?}: {e}")).path();
+        if path.extension().and_then(|e| e.to_str()) != Some("txt") {
+            continue;
+        }
+        let name = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or_else(|| panic!("non-UTF8 fixture name under {dir:?}"))
+            .to_string();
+        checked += 1;
+        if let Err(diff) = check_fixture(agent_type, &name) {
+            failures.push(diff);
+        }
+    }
+
+    assert!(checked > 0, "no .txt fixtures found under {dir:?}");
+    assert!(
+        failures.is_empty(),
+        "{} of {checked} fixture(s) mismatched:\n\n{}",
+        failures.len(),
+        failures.join("\n\n")
+    );
+}
+
+#[test]
+fn codex_fixtures() {
+    run_agent_fixtures("codex");
+}
+
+#[test]
+fn claude_code_fixtures() {
+    run_agent_fixtures("claude-code");
+}
+
+#[test]
+fn opencode_fixtures() {
+    run_agent_fixtures("opencode");
+}
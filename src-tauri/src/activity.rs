@@ -0,0 +1,54 @@
+//! Periodically aggregates every known agent pane's `AgentStatus` into a
+//! single tray-wide activity summary, turning the tray into a lightweight
+//! "what are my agents doing right now" indicator (in the spirit of an
+//! editor's background-task activity icon) rather than just an unread badge.
+//!
+//! Runs independently of `[notification.status_rules]` — that subsystem
+//! fires one-shot alerts on *transitions* and is opt-in; this one just
+//! reflects current state, so it shouldn't require configuring rules.
+
+use std::time::Duration;
+
+use tauri::AppHandle;
+
+use crate::sessions;
+use crate::tray;
+
+/// How often to re-scan tmux panes. Activity display doesn't need the
+/// sub-second precision a file watcher would give it, so a plain sleep loop
+/// (same shape as `start_mute_sweep`) is enough.
+const POLL_INTERVAL: Duration = Duration::from_secs(3);
+
+pub fn start(app_handle: AppHandle) {
+    std::thread::spawn(move || loop {
+        poll_once(&app_handle);
+        std::thread::sleep(POLL_INTERVAL);
+    });
+}
+
+fn poll_once(app_handle: &AppHandle) {
+    let groups = match sessions::list_tmux_panes_grouped() {
+        Ok(g) => g,
+        Err(e) => {
+            log::debug!("[activity] list_tmux_panes_grouped failed: {}", e);
+            return;
+        }
+    };
+
+    let panes: Vec<tray::PaneActivity> = groups
+        .iter()
+        .flat_map(|group| {
+            let repo_name = group.repo_name.clone();
+            group.panes.iter().filter_map(move |pane| {
+                Some(tray::PaneActivity {
+                    pane_id: pane.pane_id.clone(),
+                    repo_name: repo_name.clone(),
+                    window_name: pane.window_name.clone(),
+                    status: pane.agent_status?,
+                })
+            })
+        })
+        .collect();
+
+    tray::update_activity(app_handle, &panes);
+}
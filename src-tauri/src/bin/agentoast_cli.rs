@@ -0,0 +1,81 @@
+//! Headless companion binary that drives an already-running `agentoast` GUI
+//! instance by forwarding argv to it over `tauri-plugin-single-instance`,
+//! instead of opening its own window or touching the DB directly.
+
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(name = "agentoast-cli", about = "Control a running agentoast instance")]
+struct Cli {
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Toggle global mute
+    ToggleMute,
+    /// Toggle mute for a single repository path
+    MuteRepo {
+        /// Filesystem path of the repository to (un)mute
+        path: String,
+    },
+    /// Show the notification panel
+    ShowPanel,
+    /// Delete all notifications
+    ClearAll,
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    let pid_path = agentoast_shared::config::data_dir().join("agentoast.pid");
+    if !pid_path.exists() {
+        eprintln!("agentoast is not running (no instance detected)");
+        std::process::exit(1);
+    }
+
+    let app_path = match sibling_app_path() {
+        Some(path) => path,
+        None => {
+            eprintln!("Could not locate the agentoast app binary");
+            std::process::exit(1);
+        }
+    };
+
+    let args: Vec<String> = match cli.command {
+        Commands::ToggleMute => vec!["toggle-mute".to_string()],
+        Commands::MuteRepo { path } => vec!["mute-repo".to_string(), path],
+        Commands::ShowPanel => vec!["show-panel".to_string()],
+        Commands::ClearAll => vec!["clear-all".to_string()],
+    };
+
+    // Launching the GUI binary again while an instance is already running
+    // causes its `tauri-plugin-single-instance` hook to forward this argv to
+    // the running instance and exit immediately, without opening a window.
+    let status = std::process::Command::new(app_path)
+        .args(&args)
+        .status();
+
+    match status {
+        Ok(status) if status.success() => {}
+        Ok(status) => std::process::exit(status.code().unwrap_or(1)),
+        Err(e) => {
+            eprintln!("Failed to forward command to agentoast: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Locates the `agentoast` GUI binary next to this one (same install/target directory).
+fn sibling_app_path() -> Option<std::path::PathBuf> {
+    let exe = std::env::current_exe().ok()?;
+    let dir = exe.parent()?;
+    let app_name = if cfg!(windows) {
+        "agentoast.exe"
+    } else {
+        "agentoast"
+    };
+    let candidate = dir.join(app_name);
+    candidate.exists().then_some(candidate)
+}
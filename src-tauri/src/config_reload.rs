@@ -0,0 +1,28 @@
+//! Applies `agentoast_shared::config_watch`'s reloads to the running app:
+//! every time the watcher parses a changed `config.toml`, refresh `AppState`
+//! so subsequent commands see it and tell the frontend, the same way a
+//! mute/unmute toggle fires `mute:changed`. A purely in-memory toggle (the
+//! `save_notification_muted`/`save_notification_filter_notified_only`
+//! writers already in `config.rs`) round-trips through this same path,
+//! since it writes the file and the watcher picks the write back up.
+
+use std::sync::Mutex;
+
+use agentoast_shared::config_watch;
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::AppState;
+
+pub fn start(app_handle: AppHandle) {
+    std::thread::spawn(move || {
+        let reloads = config_watch::watch_default_config();
+        for config in reloads {
+            log::info!("config.toml changed, reloading");
+            let state = app_handle.state::<Mutex<AppState>>();
+            if let Ok(mut state) = state.lock() {
+                state.config = config;
+            }
+            let _ = app_handle.emit("config:changed", ());
+        }
+    });
+}
@@ -1,23 +1,31 @@
 #[cfg(target_os = "macos")]
+mod activity;
+#[cfg(target_os = "macos")]
 mod app_nap;
+mod config_reload;
 mod panel;
 #[cfg(target_os = "macos")]
 mod sessions;
 #[cfg(target_os = "macos")]
 mod terminal;
+#[cfg(target_os = "macos")]
+mod status_watch;
 mod toast;
 mod tray;
+mod updater;
 mod watcher;
 #[cfg(target_os = "macos")]
 mod webkit_config;
 
-use std::collections::HashSet;
+use std::collections::HashMap;
+use std::process;
 use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
-use agentoast_shared::config::{self, AppConfig};
+use agentoast_shared::config::{self, AppConfig, KeyAction};
 use agentoast_shared::db;
 use agentoast_shared::models::{Notification, TmuxPaneGroup};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use tauri::{Emitter, Manager};
 use tauri_plugin_global_shortcut::{GlobalShortcutExt, ShortcutState};
 
@@ -26,35 +34,404 @@ pub struct AppState {
     pub config: AppConfig,
 }
 
+/// Mute state for the panel/toast pipeline. A `None` expiry means "muted
+/// until explicitly unmuted"; `Some(Instant)` is a timed snooze, swept by
+/// `start_mute_sweep` once it elapses.
 #[derive(Default)]
 pub struct MuteState {
     pub global_muted: bool,
-    pub muted_repos: HashSet<String>,
+    pub global_mute_until: Option<Instant>,
+    pub muted_repos: HashMap<String, Option<Instant>>,
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepoMutePayload {
+    pub path: String,
+    pub remaining_secs: Option<u64>,
 }
 
 #[derive(Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MuteStatePayload {
     pub global_muted: bool,
-    pub muted_repos: Vec<String>,
+    pub global_mute_remaining_secs: Option<u64>,
+    pub muted_repos: Vec<RepoMutePayload>,
 }
 
 impl MuteState {
     pub fn to_payload(&self) -> MuteStatePayload {
-        let mut repos: Vec<String> = self.muted_repos.iter().cloned().collect();
-        repos.sort();
+        let now = Instant::now();
+        let mut repos: Vec<RepoMutePayload> = self
+            .muted_repos
+            .iter()
+            .map(|(path, until)| RepoMutePayload {
+                path: path.clone(),
+                remaining_secs: until.map(|u| u.saturating_duration_since(now).as_secs()),
+            })
+            .collect();
+        repos.sort_by(|a, b| a.path.cmp(&b.path));
         MuteStatePayload {
             global_muted: self.global_muted,
+            global_mute_remaining_secs: self
+                .global_mute_until
+                .map(|u| u.saturating_duration_since(now).as_secs()),
             muted_repos: repos,
         }
     }
+
+    /// Mutes `path` for `minutes` (0 means indefinitely, matching the
+    /// permanent-mute behavior of the plain toggle).
+    pub fn mute_repo_for(&mut self, path: String, minutes: u64) {
+        let until = (minutes > 0).then(|| Instant::now() + Duration::from_secs(minutes * 60));
+        self.muted_repos.insert(path, until);
+    }
+
+    /// Mutes everything for `minutes` (0 means indefinitely).
+    pub fn snooze_global(&mut self, minutes: u64) {
+        self.global_muted = true;
+        self.global_mute_until =
+            (minutes > 0).then(|| Instant::now() + Duration::from_secs(minutes * 60));
+    }
+
+    /// Flips any expired snooze back to unmuted. Returns whether anything changed.
+    pub fn sweep_expired(&mut self) -> bool {
+        let now = Instant::now();
+        let mut changed = false;
+
+        if self.global_muted {
+            if let Some(until) = self.global_mute_until {
+                if until <= now {
+                    self.global_muted = false;
+                    self.global_mute_until = None;
+                    changed = true;
+                }
+            }
+        }
+
+        let before = self.muted_repos.len();
+        self.muted_repos
+            .retain(|_, until| until.map_or(true, |u| u > now));
+        if self.muted_repos.len() != before {
+            changed = true;
+        }
+
+        changed
+    }
+}
+
+/// On-disk snapshot of `MuteState`, so active snoozes survive a restart.
+/// `Instant` has no stable epoch, so expiries are converted to/from Unix
+/// seconds at the persistence boundary.
+#[derive(Default, Serialize, Deserialize)]
+struct PersistedMuteState {
+    global_muted: bool,
+    global_mute_until_epoch_secs: Option<u64>,
+    muted_repos: Vec<PersistedRepoMute>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PersistedRepoMute {
+    path: String,
+    until_epoch_secs: Option<u64>,
+}
+
+fn mute_state_path() -> std::path::PathBuf {
+    config::data_dir().join("mute_state.json")
+}
+
+fn instant_to_epoch_secs(deadline: Instant) -> u64 {
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    (SystemTime::now() + remaining)
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Returns `None` if the deadline has already passed (the caller should drop
+/// the entry rather than resurrect it with a zero/negative remaining time).
+fn epoch_secs_to_instant(epoch_secs: u64) -> Option<Instant> {
+    let target = UNIX_EPOCH + Duration::from_secs(epoch_secs);
+    target
+        .duration_since(SystemTime::now())
+        .ok()
+        .map(|remaining| Instant::now() + remaining)
+}
+
+fn persist_mute_state(state: &MuteState) {
+    let persisted = PersistedMuteState {
+        global_muted: state.global_muted,
+        global_mute_until_epoch_secs: state.global_mute_until.map(instant_to_epoch_secs),
+        muted_repos: state
+            .muted_repos
+            .iter()
+            .map(|(path, until)| PersistedRepoMute {
+                path: path.clone(),
+                until_epoch_secs: until.map(instant_to_epoch_secs),
+            })
+            .collect(),
+    };
+
+    match serde_json::to_string(&persisted) {
+        Ok(json) => {
+            if let Err(e) = std::fs::write(mute_state_path(), json) {
+                log::warn!("Failed to persist mute state: {}", e);
+            }
+        }
+        Err(e) => log::warn!("Failed to serialize mute state: {}", e),
+    }
+}
+
+/// Loads the persisted snoozes, dropping any that already expired while the
+/// app was closed.
+fn load_persisted_mute_state() -> MuteState {
+    let content = match std::fs::read_to_string(mute_state_path()) {
+        Ok(c) => c,
+        Err(_) => return MuteState::default(),
+    };
+    let persisted: PersistedMuteState = match serde_json::from_str(&content) {
+        Ok(p) => p,
+        Err(e) => {
+            log::warn!("Failed to parse persisted mute state: {}", e);
+            return MuteState::default();
+        }
+    };
+
+    let global_mute_until = persisted.global_mute_until_epoch_secs.and_then(epoch_secs_to_instant);
+    let global_muted = persisted.global_muted
+        && (persisted.global_mute_until_epoch_secs.is_none() || global_mute_until.is_some());
+
+    let muted_repos = persisted
+        .muted_repos
+        .into_iter()
+        .filter_map(|r| match r.until_epoch_secs {
+            None => Some((r.path, None)),
+            Some(secs) => epoch_secs_to_instant(secs).map(|until| (r.path, Some(until))),
+        })
+        .collect();
+
+    MuteState {
+        global_muted,
+        global_mute_until,
+        muted_repos,
+    }
+}
+
+/// Spawns the background sweep that flips expired snoozes back to unmuted,
+/// on the same thread-plus-sleep infrastructure `watcher::start` uses.
+fn start_mute_sweep(app_handle: tauri::AppHandle) {
+    std::thread::spawn(move || loop {
+        std::thread::sleep(Duration::from_secs(15));
+
+        let mute_state = app_handle.state::<Mutex<MuteState>>();
+        let payload = {
+            let mut state = match mute_state.lock() {
+                Ok(s) => s,
+                Err(e) => {
+                    log::error!("mute sweep: failed to lock MuteState: {}", e);
+                    continue;
+                }
+            };
+            if !state.sweep_expired() {
+                continue;
+            }
+            persist_mute_state(&state);
+            state.to_payload()
+        };
+
+        let _ = app_handle.emit("mute:changed", &payload);
+        tray::update_mute_menu(&app_handle, payload.global_muted);
+    });
+}
+
+/// Actions the headless `agentoast-cli` companion binary can forward to an
+/// already-running instance via `tauri-plugin-single-instance`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliAction {
+    ToggleMute,
+    MuteRepo(String),
+    ShowPanel,
+    ClearAll,
+}
+
+/// Parses the argv forwarded by a second app launch (argv[0] is the
+/// executable path, so the action is argv[1]).
+pub fn parse_cli_action(argv: &[String]) -> Option<CliAction> {
+    match argv.get(1).map(|s| s.as_str()) {
+        Some("toggle-mute") => Some(CliAction::ToggleMute),
+        Some("mute-repo") => argv.get(2).map(|path| CliAction::MuteRepo(path.clone())),
+        Some("show-panel") => Some(CliAction::ShowPanel),
+        Some("clear-all") => Some(CliAction::ClearAll),
+        _ => None,
+    }
+}
+
+fn dispatch_cli_action(app_handle: &tauri::AppHandle, action: CliAction) {
+    match action {
+        CliAction::ToggleMute => {
+            if let Err(e) = do_toggle_global_mute(app_handle) {
+                log::error!("CLI toggle-mute failed: {}", e);
+            }
+        }
+        CliAction::MuteRepo(repo_path) => {
+            let mute_state = app_handle.state::<Mutex<MuteState>>();
+            if let Ok(mut state) = mute_state.lock() {
+                if state.muted_repos.contains_key(&repo_path) {
+                    state.muted_repos.remove(&repo_path);
+                } else {
+                    state.muted_repos.insert(repo_path, None);
+                }
+                let payload = state.to_payload();
+                persist_mute_state(&state);
+                let _ = app_handle.emit("mute:changed", &payload);
+            }
+        }
+        CliAction::ShowPanel => show_panel(app_handle.clone()),
+        CliAction::ClearAll => {
+            let state = app_handle.state::<Mutex<AppState>>();
+            if let Err(e) = delete_all_notifications(app_handle.clone(), state) {
+                log::error!("CLI clear-all failed: {}", e);
+            }
+        }
+    }
+}
+
+fn dispatch_shortcut_action(app_handle: &tauri::AppHandle, action: KeyAction) {
+    match action {
+        KeyAction::TogglePanel => tray::toggle_panel(app_handle),
+        KeyAction::ToggleMute => {
+            if let Err(e) = do_toggle_global_mute(app_handle) {
+                log::error!("toggle-mute shortcut failed: {}", e);
+            }
+        }
+        KeyAction::ClearAll => {
+            let state = app_handle.state::<Mutex<AppState>>();
+            if let Err(e) = delete_all_notifications(app_handle.clone(), state) {
+                log::error!("clear-all shortcut failed: {}", e);
+            }
+        }
+        KeyAction::FocusLatest => focus_latest_notification(app_handle),
+        KeyAction::ToggleFilterNotifiedOnly => toggle_filter_notified_only_shortcut(app_handle),
+        KeyAction::FocusTerminal => focus_frontmost_terminal(),
+        KeyAction::DismissToast => toast::hide(app_handle),
+        KeyAction::OpenConfig => open_config_in_editor(),
+    }
+}
+
+/// Flips `notification.filter_notified_only` for the keybinding path: updates
+/// the in-memory config, persists it via `save_notification_filter_notified_only`
+/// and emits `filter-notified-only:changed`. (The `save_filter_notified_only`
+/// command writes `panel.filter_notified_only` instead.)
+fn toggle_filter_notified_only_shortcut(app_handle: &tauri::AppHandle) {
+    let state = app_handle.state::<Mutex<AppState>>();
+    let new_value = match state.lock() {
+        Ok(mut state) => {
+            let new_value = !state.config.notification.filter_notified_only;
+            state.config.notification.filter_notified_only = new_value;
+            new_value
+        }
+        Err(e) => {
+            log::error!("toggle-filter-notified-only: failed to lock state: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = config::save_notification_filter_notified_only(new_value) {
+        log::warn!("Failed to save filter_notified_only to config.toml: {}", e);
+    }
+    let _ = app_handle.emit("filter-notified-only:changed", new_value);
+}
+
+/// Focuses the terminal of the front-most monitored pane (the first entry
+/// `list_tmux_panes_grouped` returns), unlike `focus_latest_notification`
+/// which focuses whatever pane the most recent DB notification recorded.
+fn focus_frontmost_terminal() {
+    #[cfg(target_os = "macos")]
+    {
+        let pane = match sessions::list_tmux_panes_grouped() {
+            Ok(groups) => groups.into_iter().flat_map(|g| g.panes).next(),
+            Err(e) => {
+                log::error!("focus-terminal: failed to list tmux panes: {}", e);
+                return;
+            }
+        };
+        let Some(pane) = pane else {
+            return;
+        };
+        if let Err(e) = sessions::focus_pane(&pane.pane_id) {
+            log::error!("focus-terminal: {}", e);
+        }
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        log::warn!("focus-terminal is only supported on macOS");
+    }
+}
+
+/// Opens config.toml in the configured editor, the same way the `agentoast
+/// config` CLI subcommand does, but without blocking the app on the child
+/// process.
+fn open_config_in_editor() {
+    let config_path = match config::ensure_config_file() {
+        Ok(p) => p,
+        Err(e) => {
+            log::error!("open-config: failed to create config file: {}", e);
+            return;
+        }
+    };
+    let editor = config::resolve_editor();
+    if let Err(e) = std::process::Command::new("sh")
+        .arg("-c")
+        .arg(format!("{} \"{}\"", editor, config_path.display()))
+        .spawn()
+    {
+        log::error!("open-config: failed to launch editor '{}': {}", editor, e);
+    }
+}
+
+/// Focuses the terminal pane/bundle recorded on the most recently created notification.
+fn focus_latest_notification(app_handle: &tauri::AppHandle) {
+    let state = app_handle.state::<Mutex<AppState>>();
+    let db_path = match state.lock() {
+        Ok(s) => s.db_path.clone(),
+        Err(e) => {
+            log::error!("focus-latest: failed to lock state: {}", e);
+            return;
+        }
+    };
+    let conn = match db::open_reader(&db_path) {
+        Ok(c) => c,
+        Err(e) => {
+            log::error!("focus-latest: failed to open database: {}", e);
+            return;
+        }
+    };
+    let notifications = match db::get_notifications(&conn, 1) {
+        Ok(n) => n,
+        Err(e) => {
+            log::error!("focus-latest: failed to query notifications: {}", e);
+            return;
+        }
+    };
+    let Some(n) = notifications.first() else {
+        return;
+    };
+    #[cfg(target_os = "macos")]
+    if let Err(e) = terminal::focus_terminal(&n.tmux_pane, &n.terminal_bundle_id) {
+        log::error!("focus-latest: {}", e);
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        log::warn!("focus-latest is only supported on macOS");
+    }
 }
 
 pub fn do_toggle_global_mute(app_handle: &tauri::AppHandle) -> Result<MuteStatePayload, String> {
     let mute_state = app_handle.state::<Mutex<MuteState>>();
     let mut state = mute_state.lock().map_err(|e| e.to_string())?;
     state.global_muted = !state.global_muted;
+    state.global_mute_until = None;
     let payload = state.to_payload();
+    persist_mute_state(&state);
     let _ = app_handle.emit("mute:changed", &payload);
     tray::update_mute_menu(app_handle, payload.global_muted);
     if let Err(e) = config::save_panel_muted(payload.global_muted) {
@@ -104,6 +481,18 @@ fn focus_terminal(tmux_pane: String, terminal_bundle_id: String) -> Result<(), S
     }
 }
 
+#[tauri::command]
+fn focus_previous() -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        terminal::focus_previous()
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        Err("focus_previous is only supported on macOS".to_string())
+    }
+}
+
 #[tauri::command]
 fn get_sessions() -> Result<Vec<TmuxPaneGroup>, String> {
     #[cfg(target_os = "macos")]
@@ -116,6 +505,19 @@ fn get_sessions() -> Result<Vec<TmuxPaneGroup>, String> {
     }
 }
 
+#[tauri::command]
+fn focus_pane(pane_id: String) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        sessions::focus_pane(&pane_id)
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = pane_id;
+        Err("Sessions are only supported on macOS".to_string())
+    }
+}
+
 #[tauri::command]
 fn get_notifications(
     state: tauri::State<'_, Mutex<AppState>>,
@@ -199,13 +601,47 @@ fn toggle_repo_mute(
     repo_path: String,
 ) -> Result<MuteStatePayload, String> {
     let mut state = state.lock().map_err(|e| e.to_string())?;
-    if state.muted_repos.contains(&repo_path) {
+    if state.muted_repos.contains_key(&repo_path) {
         state.muted_repos.remove(&repo_path);
     } else {
-        state.muted_repos.insert(repo_path);
+        state.muted_repos.insert(repo_path, None);
     }
     let payload = state.to_payload();
+    persist_mute_state(&state);
+    let _ = app_handle.emit("mute:changed", &payload);
+    Ok(payload)
+}
+
+/// Mutes `repo_path` for `minutes` (0 mutes indefinitely).
+#[tauri::command]
+fn mute_repo_for(
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, Mutex<MuteState>>,
+    repo_path: String,
+    minutes: u64,
+) -> Result<MuteStatePayload, String> {
+    let mut state = state.lock().map_err(|e| e.to_string())?;
+    state.mute_repo_for(repo_path, minutes);
+    let payload = state.to_payload();
+    persist_mute_state(&state);
+    let _ = app_handle.emit("mute:changed", &payload);
+    Ok(payload)
+}
+
+/// Mutes everything for `minutes` (0 mutes indefinitely).
+#[tauri::command]
+fn snooze_global(
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, Mutex<MuteState>>,
+    minutes: u64,
+) -> Result<MuteStatePayload, String> {
+    let mut state = state.lock().map_err(|e| e.to_string())?;
+    state.snooze_global(minutes);
+    let payload = state.to_payload();
+    persist_mute_state(&state);
+    drop(state);
     let _ = app_handle.emit("mute:changed", &payload);
+    tray::update_mute_menu(&app_handle, payload.global_muted);
     Ok(payload)
 }
 
@@ -287,16 +723,39 @@ pub fn run() {
         .apply()
         .expect("Failed to initialize logger");
 
+    let updater_feed_url = config::load_config().updater.feed_url;
+
     tauri::Builder::default()
+        .plugin(tauri_plugin_single_instance::init(|app, argv, _cwd| {
+            if let Some(action) = parse_cli_action(&argv) {
+                dispatch_cli_action(app, action);
+            } else {
+                log::warn!("Ignoring unrecognized CLI forward: {:?}", argv);
+            }
+        }))
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_nspanel::init())
+        .plugin({
+            let mut builder = tauri_plugin_updater::Builder::new();
+            match updater_feed_url.parse() {
+                Ok(url) => builder = builder.endpoints(vec![url]),
+                Err(e) => log::warn!(
+                    "Invalid updater feed_url '{}': {}, using plugin defaults",
+                    updater_feed_url,
+                    e
+                ),
+            }
+            builder.build()
+        })
         .invoke_handler(tauri::generate_handler![
             init_panel,
             hide_panel,
             hide_toast,
             show_panel,
             focus_terminal,
+            focus_previous,
             get_sessions,
+            focus_pane,
             get_notifications,
             get_unread_count,
             delete_notification,
@@ -310,6 +769,8 @@ pub fn run() {
             get_mute_state,
             toggle_global_mute,
             toggle_repo_mute,
+            mute_repo_for,
+            snooze_global,
         ])
         .setup(|app| {
             #[cfg(target_os = "macos")]
@@ -326,8 +787,17 @@ pub fn run() {
             log::info!("DB path: {:?}", db_path);
             log::info!("Config: {:?}", app_config);
 
-            let shortcut_str = app_config.shortcut.toggle_panel.clone();
+            let keybindings = app_config.keybinding.parse();
+            let shortcut_bindings: Vec<(KeyAction, String)> = KeyAction::ALL
+                .into_iter()
+                .filter_map(|action| {
+                    keybindings.get(action).map(|chord| (action, chord.to_string()))
+                })
+                .collect();
             let initial_muted = app_config.panel.muted;
+            let update_auto_check_interval_secs = app_config.updater.auto_check_interval_secs;
+            #[cfg(target_os = "macos")]
+            let status_poll_interval_secs = app_config.notification.status_rules.poll_interval_secs;
 
             // Ensure DB is initialized
             let _ = db::open(&db_path).expect("Failed to initialize database");
@@ -337,49 +807,65 @@ pub fn run() {
                 config: app_config,
             }));
 
-            app.manage(Mutex::new(MuteState {
-                global_muted: initial_muted,
-                muted_repos: HashSet::new(),
-            }));
+            // Restore any snoozes that survived a restart; config.toml's permanent
+            // mute flag still mutes globally whenever the restored state isn't
+            // already globally muted.
+            let mut mute_state = load_persisted_mute_state();
+            if !mute_state.global_muted && initial_muted {
+                mute_state.global_muted = true;
+            }
+            let initial_muted = mute_state.global_muted;
+            app.manage(Mutex::new(mute_state));
 
             tray::create(app.handle())?;
             if initial_muted {
                 tray::update_mute_menu(app.handle(), true);
             }
 
-            // Register global shortcut for panel toggle
-            if !shortcut_str.is_empty() {
+            // Register each configured global shortcut. An invalid or empty entry is
+            // skipped individually so one bad binding doesn't disable the rest.
+            let mut registered_shortcuts: Vec<(tauri_plugin_global_shortcut::Shortcut, KeyAction, String)> =
+                Vec::new();
+            for (action, shortcut_str) in shortcut_bindings {
+                if shortcut_str.is_empty() {
+                    continue;
+                }
                 match shortcut_str.parse::<tauri_plugin_global_shortcut::Shortcut>() {
-                    Ok(shortcut) => {
-                        app.handle().plugin(
-                            tauri_plugin_global_shortcut::Builder::new()
-                                .with_handler(move |app, sc, event| {
-                                    if event.state == ShortcutState::Pressed && sc == &shortcut {
-                                        tray::toggle_panel(app);
-                                    }
-                                })
-                                .build(),
-                        )?;
-                        if let Err(e) = app.global_shortcut().register(shortcut) {
-                            log::error!(
-                                "Failed to register global shortcut '{}': {}",
-                                shortcut_str,
-                                e
-                            );
-                        } else {
-                            log::info!("Global shortcut registered: {}", shortcut_str);
-                        }
-                    }
+                    Ok(shortcut) => registered_shortcuts.push((shortcut, action, shortcut_str)),
                     Err(e) => {
                         log::warn!(
-                            "Invalid shortcut '{}' in config.toml: {}, shortcut disabled",
+                            "Invalid shortcut '{}' in config.toml: {}, skipping",
                             shortcut_str,
                             e
                         );
                     }
                 }
+            }
+
+            if !registered_shortcuts.is_empty() {
+                let bindings = registered_shortcuts.clone();
+                app.handle().plugin(
+                    tauri_plugin_global_shortcut::Builder::new()
+                        .with_handler(move |app, sc, event| {
+                            if event.state != ShortcutState::Pressed {
+                                return;
+                            }
+                            if let Some((_, action, _)) = bindings.iter().find(|(bound, _, _)| bound == sc)
+                            {
+                                dispatch_shortcut_action(app, *action);
+                            }
+                        })
+                        .build(),
+                )?;
+                for (shortcut, _, shortcut_str) in registered_shortcuts {
+                    if let Err(e) = app.global_shortcut().register(shortcut) {
+                        log::error!("Failed to register global shortcut '{}': {}", shortcut_str, e);
+                    } else {
+                        log::info!("Global shortcut registered: {}", shortcut_str);
+                    }
+                }
             } else {
-                log::info!("Global shortcut disabled (empty string in config)");
+                log::info!("No global shortcuts configured");
             }
 
             // Initialize toast panel
@@ -388,9 +874,34 @@ pub fn run() {
             // Start DB watcher
             watcher::start(app.handle().clone(), db_path);
 
+            // Hot-reload config.toml so toggles and external edits apply live
+            config_reload::start(app.handle().clone());
+
+            // Start periodic update check
+            updater::start_periodic(app.handle().clone(), update_auto_check_interval_secs);
+
+            // Sweep expired mute snoozes
+            start_mute_sweep(app.handle().clone());
+
+            // Watch for agent status transitions (Running→Waiting/Idle)
+            #[cfg(target_os = "macos")]
+            status_watch::start(app.handle().clone(), status_poll_interval_secs);
+
+            // Reflect live agent activity in the tray icon/tooltip/submenu
+            #[cfg(target_os = "macos")]
+            activity::start(app.handle().clone());
+
+            // Marker the headless `agentoast-cli` companion checks before forwarding,
+            // so it can fail fast with an error instead of spawning a fresh instance.
+            let _ = std::fs::write(config::data_dir().join("agentoast.pid"), process::id().to_string());
+
             Ok(())
         })
         .build(tauri::generate_context!())
         .expect("error while building tauri application")
-        .run(|_, _| {});
+        .run(|_app_handle, event| {
+            if let tauri::RunEvent::Exit = event {
+                let _ = std::fs::remove_file(config::data_dir().join("agentoast.pid"));
+            }
+        });
 }
@@ -0,0 +1,157 @@
+//! Unix-domain-socket control API for the native toast: lets an external
+//! process (a shell hook, another agent) enqueue or dismiss toasts without
+//! linking against the GUI crate. One newline-delimited JSON message per
+//! request, one newline-delimited JSON `ControlResponse` back.
+//!
+//! Every operation is marshalled onto the main thread via
+//! `AppHandle::run_on_main_thread` -- the same mechanism `watcher`'s
+//! notification-delivery path already uses to reach AppKit calls from a
+//! background thread -- and calls straight into `native_toast`'s own public
+//! functions, so a socket-driven dismiss and a click-driven dismiss share
+//! the exact same `TOAST_STATE` and handlers.
+
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::time::Duration;
+
+use agentoast_shared::config;
+use agentoast_shared::models::Notification;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum ControlMessage {
+    Enqueue { notification: Notification },
+    DismissKeep,
+    DismissDelete,
+    Advance,
+    Clear,
+}
+
+#[derive(Debug, Serialize)]
+struct ControlResponse {
+    status: &'static str,
+    unread_count: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Binds the control socket and spawns a thread that serves it for the
+/// lifetime of the app. A bind failure (permissions, an already-running
+/// instance) is logged and non-fatal -- the toast subsystem works the same
+/// without it, just without the socket API.
+pub(crate) fn init(app_handle: &tauri::AppHandle) {
+    let path = config::toast_socket_path();
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    // A stale socket left behind by an uncleanly-terminated previous run
+    // would otherwise make bind() fail with AddrInUse.
+    let _ = std::fs::remove_file(&path);
+
+    let listener = match UnixListener::bind(&path) {
+        Ok(l) => l,
+        Err(e) => {
+            log::error!(
+                "[native_toast] failed to bind control socket at {}: {}",
+                path.display(),
+                e
+            );
+            return;
+        }
+    };
+
+    let app_handle = app_handle.clone();
+    let spawned = std::thread::Builder::new()
+        .name("native-toast-control".into())
+        .spawn(move || {
+            for stream in listener.incoming() {
+                match stream {
+                    Ok(stream) => {
+                        let app_handle = app_handle.clone();
+                        std::thread::spawn(move || handle_connection(stream, app_handle));
+                    }
+                    Err(e) => log::debug!("[native_toast] control socket accept error: {}", e),
+                }
+            }
+        });
+
+    if let Err(e) = spawned {
+        log::error!("[native_toast] failed to spawn control socket thread: {}", e);
+    }
+}
+
+fn handle_connection(stream: UnixStream, app_handle: tauri::AppHandle) {
+    let reader = BufReader::new(match stream.try_clone() {
+        Ok(s) => s,
+        Err(_) => return,
+    });
+    let mut writer = stream;
+
+    for line in reader.lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = match serde_json::from_str::<ControlMessage>(&line) {
+            Ok(message) => apply(message, &app_handle),
+            Err(e) => ControlResponse {
+                status: "error",
+                unread_count: current_unread_count(),
+                error: Some(format!("invalid message: {e}")),
+            },
+        };
+
+        let Ok(mut json) = serde_json::to_string(&response) else {
+            continue;
+        };
+        json.push('\n');
+        if writer.write_all(json.as_bytes()).is_err() {
+            break;
+        }
+    }
+}
+
+/// Runs `message`'s effect on the main thread and waits (briefly) for it to
+/// finish before reading back the unread count, so the reply reflects the
+/// state the operation actually produced rather than a race with it.
+fn apply(message: ControlMessage, app_handle: &tauri::AppHandle) -> ControlResponse {
+    let (done_tx, done_rx) = std::sync::mpsc::channel();
+    let dispatched = app_handle.run_on_main_thread(move || {
+        match message {
+            ControlMessage::Enqueue { notification } => {
+                super::show_notifications(vec![notification]);
+            }
+            ControlMessage::DismissKeep => super::dismiss_keep(),
+            ControlMessage::DismissDelete => super::dismiss_delete(),
+            ControlMessage::Advance => super::advance(),
+            ControlMessage::Clear => super::hide(),
+        }
+        let _ = done_tx.send(());
+    });
+
+    if dispatched.is_err() {
+        return ControlResponse {
+            status: "error",
+            unread_count: current_unread_count(),
+            error: Some("failed to dispatch to main thread".to_string()),
+        };
+    }
+
+    let _ = done_rx.recv_timeout(Duration::from_secs(2));
+
+    ControlResponse {
+        status: "ok",
+        unread_count: current_unread_count(),
+        error: None,
+    }
+}
+
+fn current_unread_count() -> i64 {
+    agentoast_shared::db::open_reader(&config::db_path())
+        .and_then(|conn| agentoast_shared::db::get_unread_count(&conn))
+        .unwrap_or(0)
+}
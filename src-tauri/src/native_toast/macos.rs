@@ -0,0 +1,1760 @@
+#![allow(deprecated)] // msg_send_id! is deprecated in objc2 0.6, but still works
+
+use std::sync::{Mutex, OnceLock};
+use std::time::Instant;
+
+use agentoast_shared::config;
+use agentoast_shared::models::Notification;
+use block2::RcBlock;
+use objc2::rc::Retained;
+use objc2::runtime::Bool;
+use objc2::{msg_send, msg_send_id, AnyThread, ClassType, MainThreadOnly};
+use objc2_app_kit::{
+    NSAnimationContext, NSApplication, NSBackingStoreType, NSColor, NSEvent, NSEventMask,
+    NSEventModifierFlags, NSEventType, NSFont,
+    NSImage, NSImageView, NSLineBreakMode, NSPanel, NSScreen, NSTextAlignment, NSTextField, NSView,
+    NSVisualEffectBlendingMode, NSVisualEffectMaterial, NSVisualEffectView,
+    NSWindowCollectionBehavior, NSWindowStyleMask,
+};
+use objc2_core_foundation::{CGPoint, CGRect, CGSize};
+use objc2_foundation::{MainThreadMarker, NSData, NSString, NSTimer};
+use tauri::Emitter;
+
+use crate::terminal;
+
+use super::shared::{
+    self, ToastColors, ToastState, BODY_LINE_HEIGHT, BODY_WIDTH, BODY_X, CORNER_RADIUS,
+    FADE_DURATION, LINE1_HEIGHT, LINE_GAP, MAX_BODY_LINES, META_HEIGHT, PADDING, PANEL_WIDTH,
+    STACK_GAP, STACK_OVERFLOW_PILL_HEIGHT, TOP_MARGIN,
+};
+use super::ToastBackend;
+
+// SAFETY: NSPanel and NSTimer are only ever accessed from the main thread.
+// We enforce this by only calling show_notifications / hide / init from the main thread.
+struct SendSyncWrapper<T>(T);
+unsafe impl<T> Send for SendSyncWrapper<T> {}
+unsafe impl<T> Sync for SendSyncWrapper<T> {}
+
+static TOAST_PANEL: OnceLock<SendSyncWrapper<Retained<NSPanel>>> = OnceLock::new();
+static TOAST_STATE: OnceLock<Mutex<ToastState>> = OnceLock::new();
+static APP_HANDLE: OnceLock<tauri::AppHandle> = OnceLock::new();
+static TOAST_TIMER: Mutex<Option<SendSyncWrapper<Retained<NSTimer>>>> = Mutex::new(None);
+static FADE_TIMER: Mutex<Option<SendSyncWrapper<Retained<NSTimer>>>> = Mutex::new(None);
+static EVENT_MONITOR_INSTALLED: OnceLock<()> = OnceLock::new();
+static HOVER_MONITOR_INSTALLED: OnceLock<()> = OnceLock::new();
+static KEY_MONITOR_INSTALLED: OnceLock<()> = OnceLock::new();
+static HOVERING: Mutex<bool> = Mutex::new(false);
+static CARD_PRESS: Mutex<Option<CardPress>> = Mutex::new(None);
+
+/// A mouse-down recorded on the card region (not a dismiss button), waiting
+/// to be classified as a short or long press on the matching mouse-up.
+struct CardPress {
+    queue_index: usize,
+    started_at: Instant,
+    location: CGPoint,
+    option_held: bool,
+}
+
+/// Window-local vertical span of each stacked card currently on screen, laid
+/// out by `build_stack_view` and consulted by the event monitor to resolve a
+/// click's `location.y` to a `queue` index before applying the existing
+/// dismiss-zone/card-click hit-testing within that card.
+static STACK_LAYOUT: Mutex<Vec<StackSlot>> = Mutex::new(Vec::new());
+
+struct StackSlot {
+    queue_index: usize,
+    y: f64,
+    height: f64,
+}
+
+/// Beyond this many points of movement between mouse-down and mouse-up, the
+/// gesture is treated as a drag and cancelled rather than a click/hold.
+const CARD_PRESS_DRAG_TOLERANCE: f64 = 6.0;
+/// Time left on the auto-advance timer at the moment the pointer entered the
+/// toast, so `set_hover(false)` can resume with exactly that much instead of
+/// restarting the full duration.
+static HOVER_REMAINING_MS: Mutex<Option<u64>> = Mutex::new(None);
+
+const GIT_BRANCH_ICON: &[u8] = include_bytes!("../../icons/toast/git-branch.png");
+const TMUX_ICON: &[u8] = include_bytes!("../../icons/toast/tmux.png");
+const X_ICON: &[u8] = include_bytes!("../../icons/toast/x.png");
+const TRASH_ICON: &[u8] = include_bytes!("../../icons/toast/trash.png");
+
+/// AppKit implementation of [`ToastBackend`]: a borderless, non-activating
+/// `NSPanel` rendered and positioned with manual `NSView`/`CALayer` layout.
+pub(crate) struct MacosBackend;
+
+impl ToastBackend for MacosBackend {
+    fn init(&self, app_handle: &tauri::AppHandle) -> Result<(), String> {
+        init(app_handle)
+    }
+
+    fn show_notifications(&self, notifications: Vec<Notification>) {
+        show_notifications(notifications)
+    }
+
+    fn hide(&self) {
+        hide()
+    }
+
+    fn dismiss_keep(&self) {
+        control_dismiss_keep()
+    }
+
+    fn dismiss_delete(&self) {
+        control_dismiss_delete()
+    }
+
+    fn advance(&self) {
+        key_advance()
+    }
+}
+
+/// Measures rendered text width with the real `NSFont` metrics, via the same
+/// "create a label, `sizeToFit`, read the frame" idiom the meta-row layout
+/// already uses below, rather than a second attributed-string sizing path.
+fn measure_text_width(mtm: MainThreadMarker, text: &str, font: &NSFont) -> f64 {
+    if text.is_empty() {
+        return 0.0;
+    }
+    let label = make_label(
+        mtm,
+        text,
+        CGRect::new(CGPoint::new(0.0, 0.0), CGSize::new(0.0, 0.0)),
+        &NSColor::blackColor(),
+        font,
+    );
+    unsafe {
+        let _: () = msg_send![&label, sizeToFit];
+    }
+    label.frame().size.width
+}
+
+fn wrapped_line_count(
+    mtm: MainThreadMarker,
+    text: &str,
+    font: &NSFont,
+    max_width: f64,
+    max_lines: usize,
+) -> usize {
+    shared::wrapped_line_count(text, max_width, max_lines, &|s| {
+        measure_text_width(mtm, s, font)
+    })
+}
+
+fn is_dark_mode() -> bool {
+    let mtm = match MainThreadMarker::new() {
+        Some(m) => m,
+        None => return false,
+    };
+    let app = NSApplication::sharedApplication(mtm);
+    unsafe {
+        let appearance: Option<Retained<objc2_app_kit::NSAppearance>> =
+            msg_send_id![&app, effectiveAppearance];
+        if let Some(appearance) = appearance {
+            let name: Option<Retained<NSString>> = msg_send_id![&appearance, name];
+            if let Some(name) = name {
+                return name.to_string().contains("Dark");
+            }
+        }
+    }
+    false
+}
+
+fn colors() -> ToastColors {
+    shared::colors(is_dark_mode())
+}
+
+fn nscolor(r: f64, g: f64, b: f64, a: f64) -> Retained<NSColor> {
+    NSColor::colorWithSRGBRed_green_blue_alpha(r, g, b, a)
+}
+
+fn nscolor_tuple(t: (f64, f64, f64, f64)) -> Retained<NSColor> {
+    nscolor(t.0, t.1, t.2, t.3)
+}
+
+fn font_medium(size: f64) -> Retained<NSFont> {
+    // NSFontWeightMedium = 0.23
+    unsafe { msg_send_id![NSFont::class(), systemFontOfSize: size, weight: 0.23_f64] }
+}
+
+fn font_regular(size: f64) -> Retained<NSFont> {
+    NSFont::systemFontOfSize(size)
+}
+
+// --- Panel creation ---
+
+fn init(app_handle: &tauri::AppHandle) -> Result<(), String> {
+    let _ = APP_HANDLE.set(app_handle.clone());
+    let _ = TOAST_STATE.set(Mutex::new({
+        let cfg = config::load_config();
+        ToastState::new(cfg.toast.duration_ms, cfg.toast.persistent)
+    }));
+
+    let mtm = MainThreadMarker::new().ok_or_else(|| "Must be called on main thread".to_string())?;
+
+    let panel = create_panel(mtm);
+    unsafe {
+        let _: () = msg_send![&panel, setAcceptsMouseMovedEvents: Bool::YES];
+    }
+    TOAST_PANEL
+        .set(SendSyncWrapper(panel))
+        .map_err(|_| "Toast panel already initialized".to_string())?;
+
+    install_event_monitor();
+    install_hover_monitor();
+    install_key_monitor();
+
+    log::info!("[native_toast] init complete");
+    Ok(())
+}
+
+fn create_panel(mtm: MainThreadMarker) -> Retained<NSPanel> {
+    unsafe {
+        let frame = CGRect::new(CGPoint::new(0.0, 0.0), CGSize::new(PANEL_WIDTH, 144.0));
+
+        let style = NSWindowStyleMask::Borderless | NSWindowStyleMask::NonactivatingPanel;
+
+        let panel = NSPanel::initWithContentRect_styleMask_backing_defer(
+            NSPanel::alloc(mtm),
+            frame,
+            style,
+            NSBackingStoreType::Buffered,
+            false,
+        );
+
+        panel.setOpaque(false);
+        panel.setBackgroundColor(Some(&NSColor::clearColor()));
+        panel.setHasShadow(true);
+        panel.setMovable(false);
+
+        // Level: floating + 2 (above main panel). NSFloatingWindowLevel = 5
+        let _: () = msg_send![&panel, setLevel: 7i64];
+
+        // Collection behavior
+        panel.setCollectionBehavior(
+            NSWindowCollectionBehavior::CanJoinAllSpaces
+                | NSWindowCollectionBehavior::Stationary
+                | NSWindowCollectionBehavior::FullScreenAuxiliary,
+        );
+
+        // Don't steal key focus
+        let _: () = msg_send![&panel, setBecomesKeyOnlyIfNeeded: Bool::YES];
+
+        panel
+    }
+}
+
+// --- Public API ---
+
+fn show_notifications(notifications: Vec<Notification>) {
+    if notifications.is_empty() {
+        return;
+    }
+
+    let Some(state_mutex) = TOAST_STATE.get() else {
+        return;
+    };
+
+    // Clear any pending timers
+    cancel_timer();
+    cancel_fade_timer();
+
+    let mut state = match state_mutex.lock() {
+        Ok(s) => s,
+        Err(e) => {
+            log::error!("[native_toast] Failed to lock state: {}", e);
+            return;
+        }
+    };
+
+    state.merge(notifications);
+    drop(state);
+    update_and_show();
+}
+
+fn hide() {
+    let Some(wrapper) = TOAST_PANEL.get() else {
+        return;
+    };
+    let panel = &wrapper.0;
+    cancel_timer();
+    cancel_fade_timer();
+
+    panel.orderOut(None);
+
+    if let Some(state_mutex) = TOAST_STATE.get() {
+        if let Ok(mut state) = state_mutex.lock() {
+            state.reset();
+        }
+    }
+    if let Ok(mut stack_layout) = STACK_LAYOUT.lock() {
+        stack_layout.clear();
+    }
+}
+
+// --- Internal ---
+
+fn update_and_show() {
+    let Some(wrapper) = TOAST_PANEL.get() else {
+        return;
+    };
+    let panel = &wrapper.0;
+    let Some(state_mutex) = TOAST_STATE.get() else {
+        return;
+    };
+
+    let state = match state_mutex.lock() {
+        Ok(s) => s,
+        Err(_) => return,
+    };
+
+    if state.queue.get(state.current_index).is_none() {
+        drop(state);
+        hide();
+        return;
+    }
+
+    let queue = state.queue.clone();
+    let current_index = state.current_index;
+    let duration_ms = state.duration_ms;
+    let persistent = state.persistent;
+    drop(state);
+
+    let mtm = match MainThreadMarker::new() {
+        Some(m) => m,
+        None => return,
+    };
+
+    let stack_size = config::load_config().toast.stack_size;
+    let (content_view, panel_height, slots) =
+        build_stack_view(mtm, &queue, current_index, stack_size);
+
+    // Resize panel BEFORE setting content view to prevent layout distortion in release builds.
+    // Setting content view on a panel with stale size causes AppKit to resize subviews incorrectly.
+    position_panel(mtm, panel, panel_height);
+
+    if let Ok(mut stack_layout) = STACK_LAYOUT.lock() {
+        *stack_layout = slots;
+    }
+    panel.setContentView(Some(&content_view));
+
+    // Show with fade-in animation
+    panel.setAlphaValue(0.0);
+    panel.orderFrontRegardless();
+
+    let panel_ptr = Retained::as_ptr(panel) as usize;
+    NSAnimationContext::runAnimationGroup(&RcBlock::new(
+        move |context: std::ptr::NonNull<NSAnimationContext>| {
+            let ctx = unsafe { context.as_ref() };
+            ctx.setDuration(FADE_DURATION);
+            let panel_ref: &NSPanel = unsafe { &*(panel_ptr as *const NSPanel) };
+            let animator: Retained<NSPanel> = unsafe { msg_send_id![panel_ref, animator] };
+            animator.setAlphaValue(1.0);
+        },
+    ));
+
+    // Start auto-advance timer
+    if !persistent {
+        start_timer(duration_ms);
+    }
+}
+
+fn build_toast_view(
+    mtm: MainThreadMarker,
+    notification: &Notification,
+    current_index: usize,
+    queue_len: usize,
+    panel_height: f64,
+    body_lines: usize,
+) -> Retained<NSView> {
+    let colors = colors();
+    let is_focus = notification.force_focus;
+    let (bg_color, border_color) = if is_focus {
+        (colors.focus_bg, colors.focus_border)
+    } else {
+        (colors.bg, colors.border)
+    };
+
+    // Root view (transparent)
+    let root = NSView::initWithFrame(
+        NSView::alloc(mtm),
+        CGRect::new(
+            CGPoint::new(0.0, 0.0),
+            CGSize::new(PANEL_WIDTH, panel_height),
+        ),
+    );
+
+    // Visual effect view (blur background)
+    let effect_frame = CGRect::new(
+        CGPoint::new(PADDING, PADDING),
+        CGSize::new(PANEL_WIDTH - PADDING * 2.0, panel_height - PADDING * 2.0),
+    );
+    let effect_view =
+        NSVisualEffectView::initWithFrame(NSVisualEffectView::alloc(mtm), effect_frame);
+    effect_view.setMaterial(NSVisualEffectMaterial::Popover);
+    effect_view.setBlendingMode(NSVisualEffectBlendingMode::BehindWindow);
+    effect_view.setWantsLayer(true);
+
+    if let Some(layer) = effect_view.layer() {
+        layer.setCornerRadius(CORNER_RADIUS);
+        layer.setMasksToBounds(true);
+        layer.setBorderWidth(1.0);
+        let border_cg = nscolor_tuple(border_color).CGColor();
+        layer.setBorderColor(Some(&border_cg));
+        let bg_cg = nscolor_tuple(bg_color).CGColor();
+        layer.setBackgroundColor(Some(&bg_cg));
+    }
+
+    let effect_w = effect_frame.size.width;
+    let effect_h = effect_frame.size.height;
+    let muted_color = nscolor_tuple(colors.text_muted);
+
+    // --- Icon (16x16, no container, directly on effect_view) ---
+    let icon_size = 16.0;
+    let icon_x = 12.0;
+    let icon_y = effect_h - TOP_MARGIN - icon_size;
+
+    let png_bytes: &[u8] = match notification.icon.as_str() {
+        "claude-code" => include_bytes!("../../icons/toast/claude-code.png"),
+        "codex" => include_bytes!("../../icons/toast/codex.png"),
+        "opencode" => include_bytes!("../../icons/toast/opencode.png"),
+        _ => include_bytes!("../../icons/toast/agentoast.png"),
+    };
+    let ns_data = NSData::with_bytes(png_bytes);
+    let image: Option<Retained<NSImage>> =
+        unsafe { msg_send_id![NSImage::alloc(), initWithData: &*ns_data] };
+    if let Some(image) = image {
+        unsafe {
+            let _: () = msg_send![&image, setTemplate: Bool::YES];
+        }
+        let image_view = NSImageView::initWithFrame(
+            NSImageView::alloc(mtm),
+            CGRect::new(
+                CGPoint::new(icon_x, icon_y),
+                CGSize::new(icon_size, icon_size),
+            ),
+        );
+        image_view.setImage(Some(&image));
+        unsafe {
+            let _: () = msg_send![&image_view, setContentTintColor: &*muted_color];
+        }
+        effect_view.addSubview(&image_view);
+    }
+
+    // Text content starts after icon
+    let text_x = 32.0;
+    let text_width = effect_w - text_x - 12.0;
+
+    // --- Line 1: Badge + repo name + relative time ---
+    let line1_y = effect_h - TOP_MARGIN - LINE1_HEIGHT;
+    let mut line1_x = 0.0_f64;
+
+    // Badge pill
+    if !notification.badge.is_empty() {
+        let (badge_bg, badge_text) = shared::badge_colors(&notification.badge_color, &colors);
+        let (badge_pill, badge_w) = make_pill(
+            mtm,
+            &notification.badge,
+            CGPoint::new(text_x + line1_x, line1_y),
+            &nscolor_tuple(badge_text),
+            &nscolor_tuple(badge_bg),
+            10.0,
+            18.0,
+        );
+        line1_x += badge_w + 4.0;
+        effect_view.addSubview(&badge_pill);
+    }
+
+    // Repo name (plain text, no background)
+    if !notification.repo.is_empty() {
+        let repo_font = font_medium(12.0);
+        let repo_label = make_label(
+            mtm,
+            &notification.repo,
+            CGRect::new(
+                CGPoint::new(text_x + line1_x, line1_y),
+                CGSize::new(text_width - line1_x, 18.0),
+            ),
+            &nscolor_tuple(colors.text_secondary),
+            &repo_font,
+        );
+        repo_label.setLineBreakMode(NSLineBreakMode::ByTruncatingTail);
+        effect_view.addSubview(&repo_label);
+    }
+
+    // Relative time (right-aligned)
+    let time_text = shared::format_relative_time(&notification.created_at);
+    if !time_text.is_empty() {
+        let time_font = font_regular(10.0);
+        let time_label = make_label(
+            mtm,
+            &time_text,
+            CGRect::new(CGPoint::new(0.0, line1_y + 2.0), CGSize::new(200.0, 16.0)),
+            &muted_color,
+            &time_font,
+        );
+        unsafe {
+            let _: () = msg_send![&time_label, sizeToFit];
+        }
+        let fitted: CGRect = time_label.frame();
+        time_label.setFrame(CGRect::new(
+            CGPoint::new(effect_w - fitted.size.width - 12.0, line1_y + 2.0),
+            CGSize::new(fitted.size.width, 16.0),
+        ));
+        effect_view.addSubview(&time_label);
+    }
+
+    // --- Line 2: Metadata (below badge line) ---
+    let meta_y = line1_y - LINE_GAP - META_HEIGHT;
+    let meta_height = META_HEIGHT;
+    let meta_icon_size = 12.0;
+    let meta_gap = 4.0;
+    let meta_icon_text_gap = 2.0;
+
+    let mut meta_entries: Vec<(Option<&[u8]>, String)> = Vec::new();
+    for (key, value) in &notification.metadata {
+        if !value.is_empty() {
+            if key == "branch" {
+                meta_entries.push((Some(GIT_BRANCH_ICON), value.clone()));
+            } else {
+                meta_entries.push((None, format!("{}:{}", key, value)));
+            }
+        }
+    }
+    if !notification.tmux_pane.is_empty() {
+        meta_entries.push((Some(TMUX_ICON), notification.tmux_pane.clone()));
+    }
+
+    let has_meta = !meta_entries.is_empty();
+    if has_meta {
+        let meta_x = 12.0; // icon_x と同じ（アイコン左端揃え）
+        let meta_width = effect_w - meta_x - 12.0;
+        let meta_container = NSView::initWithFrame(
+            NSView::alloc(mtm),
+            CGRect::new(
+                CGPoint::new(meta_x, meta_y),
+                CGSize::new(meta_width, meta_height),
+            ),
+        );
+
+        let mut cursor_x = 0.0_f64;
+        for (icon_bytes, text) in &meta_entries {
+            if cursor_x > 0.0 {
+                cursor_x += meta_gap;
+            }
+
+            if let Some(png_bytes) = icon_bytes {
+                if let Some(icon_view) =
+                    make_meta_icon(mtm, png_bytes, cursor_x, 2.0, meta_icon_size, &muted_color)
+                {
+                    meta_container.addSubview(&icon_view);
+                    cursor_x += meta_icon_size + meta_icon_text_gap;
+                }
+            }
+
+            let meta_font = font_regular(11.0);
+            let label = make_label(
+                mtm,
+                text,
+                CGRect::new(
+                    CGPoint::new(cursor_x, 0.0),
+                    CGSize::new(text_width - cursor_x, meta_height),
+                ),
+                &muted_color,
+                &meta_font,
+            );
+            unsafe {
+                let _: () = msg_send![&label, sizeToFit];
+            }
+            let fitted: CGRect = label.frame();
+            label.setFrame(CGRect::new(
+                CGPoint::new(cursor_x, 0.0),
+                CGSize::new(fitted.size.width, meta_height),
+            ));
+            meta_container.addSubview(&label);
+            cursor_x += fitted.size.width;
+        }
+
+        effect_view.addSubview(&meta_container);
+    }
+
+    // --- Line 3: Body (height matches the measured wrap from `wrapped_line_count`) ---
+    if body_lines > 0 {
+        let body_font = font_regular(11.0);
+        let body_top = if has_meta {
+            meta_y - LINE_GAP
+        } else {
+            line1_y - LINE_GAP
+        };
+        let body_h = body_lines as f64 * BODY_LINE_HEIGHT;
+        let body_x = BODY_X; // icon_x と同じ（アイコン左端揃え）
+        let body_width = effect_w - body_x - 12.0;
+        let body_y = body_top - body_h;
+        log::debug!(
+            "[native_toast] layout: effect_h={}, line1_y={}, meta_y={}, body_top={}, body_y={}, body_h={}",
+            effect_h, line1_y, meta_y, body_top, body_y, body_h
+        );
+        let body_label = make_label(
+            mtm,
+            &notification.body,
+            CGRect::new(
+                CGPoint::new(body_x, body_y),
+                CGSize::new(body_width, body_h),
+            ),
+            &nscolor_tuple(colors.text_secondary),
+            &body_font,
+        );
+        body_label.setMaximumNumberOfLines(body_lines as isize);
+        body_label.setLineBreakMode(NSLineBreakMode::ByWordWrapping);
+        effect_view.addSubview(&body_label);
+    }
+
+    // --- Queue counter (bottom-right, plain text) ---
+    let bottom_y = 8.0;
+    if queue_len > 1 {
+        let counter_str = format!("{}/{}", current_index + 1, queue_len);
+        let counter_font = font_medium(10.0);
+        let counter_label = make_label(
+            mtm,
+            &counter_str,
+            CGRect::new(CGPoint::new(0.0, bottom_y), CGSize::new(200.0, 12.0)),
+            &muted_color,
+            &counter_font,
+        );
+        unsafe {
+            let _: () = msg_send![&counter_label, sizeToFit];
+        }
+        let fitted: CGRect = counter_label.frame();
+        counter_label.setFrame(CGRect::new(
+            CGPoint::new(effect_w - fitted.size.width - 12.0, bottom_y),
+            CGSize::new(fitted.size.width, 12.0),
+        ));
+        effect_view.addSubview(&counter_label);
+    }
+
+    // --- Focused: no history badge (bottom-right) ---
+    if is_focus {
+        let (focus_pill, focus_w) = make_pill(
+            mtm,
+            "Focused: no history",
+            CGPoint::new(0.0, 0.0),
+            &nscolor_tuple(colors.focus_badge_text),
+            &nscolor_tuple(colors.focus_badge_bg),
+            10.0,
+            14.0,
+        );
+        focus_pill.setFrameOrigin(CGPoint::new(effect_w - focus_w - 12.0, 6.0));
+        effect_view.addSubview(&focus_pill);
+    }
+
+    // --- Dismiss buttons (bottom-left, pill background) ---
+    let btn_w = 28.0;
+    let btn_h = 22.0;
+    let btn_icon_size = 14.0;
+    let btn_y = 5.0;
+    let btn_gap = 6.0;
+    if let Some(v) = make_dismiss_button(
+        mtm,
+        X_ICON,
+        8.0,
+        btn_y,
+        btn_w,
+        btn_h,
+        btn_icon_size,
+        &colors,
+    ) {
+        effect_view.addSubview(&v);
+    }
+    if let Some(v) = make_dismiss_button(
+        mtm,
+        TRASH_ICON,
+        8.0 + btn_w + btn_gap,
+        btn_y,
+        btn_w,
+        btn_h,
+        btn_icon_size,
+        &colors,
+    ) {
+        effect_view.addSubview(&v);
+    }
+
+    root.addSubview(&effect_view);
+    root
+}
+
+/// Renders up to `stack_size` queued notifications as cards stacked
+/// downward from the top (each one `build_toast_view` already knows how to
+/// draw), offset by its own height plus `STACK_GAP`. Overflow beyond
+/// `stack_size` collapses the last slot into a "+k more" pill. Returns the
+/// container view, its total height (what `position_panel` anchors), and
+/// the on-screen vertical span of each card for the event monitor's
+/// hit-testing.
+fn build_stack_view(
+    mtm: MainThreadMarker,
+    queue: &[Notification],
+    current_index: usize,
+    stack_size: usize,
+) -> (Retained<NSView>, f64, Vec<StackSlot>) {
+    let queue_len = queue.len();
+    let remaining = queue_len.saturating_sub(current_index);
+    let visible_count = stack_size.max(1).min(remaining.max(1));
+    let overflow = remaining.saturating_sub(visible_count);
+    let card_count = if overflow > 0 {
+        visible_count.saturating_sub(1).max(1)
+    } else {
+        visible_count
+    };
+
+    struct Card {
+        queue_index: usize,
+        view: Retained<NSView>,
+        height: f64,
+    }
+
+    let mut cards = Vec::with_capacity(card_count);
+    for offset in 0..card_count {
+        let queue_index = current_index + offset;
+        let Some(notification) = queue.get(queue_index) else {
+            break;
+        };
+        let has_meta = !notification.tmux_pane.is_empty()
+            || notification.metadata.values().any(|v| !v.is_empty());
+        let body_lines = if notification.body.is_empty() {
+            0
+        } else {
+            wrapped_line_count(
+                mtm,
+                &notification.body,
+                &font_regular(11.0),
+                BODY_WIDTH,
+                MAX_BODY_LINES,
+            )
+        };
+        let height = shared::compute_panel_height(has_meta, body_lines);
+        let view = build_toast_view(mtm, notification, queue_index, queue_len, height, body_lines);
+        cards.push(Card {
+            queue_index,
+            view,
+            height,
+        });
+    }
+
+    let show_pill = remaining > card_count;
+    let pill_text = format!("+{} more", remaining - card_count);
+
+    let mut total_height: f64 = cards.iter().map(|c| c.height).sum();
+    if !cards.is_empty() {
+        total_height += STACK_GAP * (cards.len() - 1) as f64;
+    }
+    if show_pill {
+        if !cards.is_empty() {
+            total_height += STACK_GAP;
+        }
+        total_height += STACK_OVERFLOW_PILL_HEIGHT;
+    }
+    total_height = total_height.max(1.0);
+
+    let container = NSView::initWithFrame(
+        NSView::alloc(mtm),
+        CGRect::new(CGPoint::new(0.0, 0.0), CGSize::new(PANEL_WIDTH, total_height)),
+    );
+
+    let mut slots = Vec::with_capacity(cards.len());
+    let mut y_cursor = total_height;
+    let mut placed_any = false;
+    for card in cards {
+        if placed_any {
+            y_cursor -= STACK_GAP;
+        }
+        y_cursor -= card.height;
+        card.view.setFrameOrigin(CGPoint::new(0.0, y_cursor));
+        container.addSubview(&card.view);
+        slots.push(StackSlot {
+            queue_index: card.queue_index,
+            y: y_cursor,
+            height: card.height,
+        });
+        placed_any = true;
+    }
+
+    if show_pill {
+        if placed_any {
+            y_cursor -= STACK_GAP;
+        }
+        y_cursor -= STACK_OVERFLOW_PILL_HEIGHT;
+        let colors = colors();
+        let (pill, pill_w) = make_pill(
+            mtm,
+            &pill_text,
+            CGPoint::new(0.0, y_cursor),
+            &nscolor_tuple(colors.badge_gray_text),
+            &nscolor_tuple(colors.badge_gray_bg),
+            11.0,
+            STACK_OVERFLOW_PILL_HEIGHT,
+        );
+        pill.setFrameOrigin(CGPoint::new((PANEL_WIDTH - pill_w) / 2.0, y_cursor));
+        container.addSubview(&pill);
+    }
+
+    (container, total_height, slots)
+}
+
+/// Resolves a click/press `location` (window-local, as returned by
+/// `NSEvent::locationInWindow`) to the stacked card it landed in, returning
+/// the notification's queue index along with its click-local coordinates in
+/// the same `effect_view`-relative space `install_event_monitor` already
+/// hit-tests dismiss buttons against in single-card mode.
+fn hit_test_stack(location: CGPoint) -> Option<(usize, f64, f64)> {
+    let slots = STACK_LAYOUT.lock().ok()?;
+    for slot in slots.iter() {
+        if location.y >= slot.y && location.y < slot.y + slot.height {
+            let local_x = location.x - PADDING;
+            let local_y = location.y - slot.y - PADDING;
+            return Some((slot.queue_index, local_x, local_y));
+        }
+    }
+    None
+}
+
+fn make_label(
+    mtm: MainThreadMarker,
+    text: &str,
+    frame: CGRect,
+    color: &NSColor,
+    font: &NSFont,
+) -> Retained<NSTextField> {
+    let label = NSTextField::initWithFrame(NSTextField::alloc(mtm), frame);
+    label.setStringValue(&NSString::from_str(text));
+    label.setBezeled(false);
+    label.setDrawsBackground(false);
+    label.setEditable(false);
+    label.setSelectable(false);
+    label.setTextColor(Some(color));
+    label.setFont(Some(font));
+    label
+}
+
+fn make_pill(
+    mtm: MainThreadMarker,
+    text: &str,
+    origin: CGPoint,
+    text_color: &NSColor,
+    bg_color: &NSColor,
+    font_size: f64,
+    pill_height: f64,
+) -> (Retained<NSView>, f64) {
+    let font = font_medium(font_size);
+    let label = make_label(mtm, text, CGRect::ZERO, text_color, &font);
+    label.setAlignment(NSTextAlignment::Center);
+    unsafe {
+        let _: () = msg_send![&label, sizeToFit];
+    }
+    let fitted: CGRect = label.frame();
+
+    let pill_w = fitted.size.width + 10.0;
+    let text_y = (pill_height - fitted.size.height) / 2.0;
+
+    label.setFrame(CGRect::new(
+        CGPoint::new(0.0, text_y),
+        CGSize::new(pill_w, fitted.size.height),
+    ));
+
+    let pill = NSView::initWithFrame(
+        NSView::alloc(mtm),
+        CGRect::new(origin, CGSize::new(pill_w, pill_height)),
+    );
+    pill.setWantsLayer(true);
+    if let Some(layer) = pill.layer() {
+        let cg = bg_color.CGColor();
+        layer.setBackgroundColor(Some(&cg));
+        layer.setCornerRadius(4.0);
+    }
+    pill.addSubview(&label);
+
+    (pill, pill_w)
+}
+
+fn make_meta_icon(
+    mtm: MainThreadMarker,
+    png_bytes: &[u8],
+    x: f64,
+    y: f64,
+    size: f64,
+    tint: &NSColor,
+) -> Option<Retained<NSImageView>> {
+    let ns_data = NSData::with_bytes(png_bytes);
+    let image: Option<Retained<NSImage>> =
+        unsafe { msg_send_id![NSImage::alloc(), initWithData: &*ns_data] };
+    image.map(|img| {
+        unsafe {
+            let _: () = msg_send![&img, setTemplate: Bool::YES];
+        }
+        let view = NSImageView::initWithFrame(
+            NSImageView::alloc(mtm),
+            CGRect::new(CGPoint::new(x, y), CGSize::new(size, size)),
+        );
+        view.setImage(Some(&img));
+        unsafe {
+            let _: () = msg_send![&view, setContentTintColor: tint];
+        }
+        view
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn make_dismiss_button(
+    mtm: MainThreadMarker,
+    png_bytes: &[u8],
+    x: f64,
+    y: f64,
+    w: f64,
+    h: f64,
+    icon_size: f64,
+    colors: &ToastColors,
+) -> Option<Retained<NSView>> {
+    let container = NSView::initWithFrame(
+        NSView::alloc(mtm),
+        CGRect::new(CGPoint::new(x, y), CGSize::new(w, h)),
+    );
+    container.setWantsLayer(true);
+    if let Some(layer) = container.layer() {
+        let bg_cg = nscolor_tuple(colors.badge_gray_bg).CGColor();
+        layer.setBackgroundColor(Some(&bg_cg));
+        layer.setCornerRadius(4.0);
+    }
+
+    let icon_x = (w - icon_size) / 2.0;
+    let icon_y = (h - icon_size) / 2.0;
+    let tint = nscolor_tuple(colors.text_muted);
+    let icon_view = make_meta_icon(mtm, png_bytes, icon_x, icon_y, icon_size, &tint)?;
+    container.addSubview(&icon_view);
+
+    Some(container)
+}
+
+// --- Click handling via local event monitor ---
+
+fn install_event_monitor() {
+    EVENT_MONITOR_INSTALLED.get_or_init(|| {
+        unsafe {
+            let block = RcBlock::new(|event: std::ptr::NonNull<NSEvent>| -> *mut NSEvent {
+                let event_ref = event.as_ref();
+                let Some(wrapper) = TOAST_PANEL.get() else {
+                    return event.as_ptr();
+                };
+                let panel = &wrapper.0;
+
+                // Check if click is within our panel
+                let event_window_num: i64 = msg_send![event_ref, windowNumber];
+                let panel_window_num: i64 = msg_send![panel, windowNumber];
+                if event_window_num != panel_window_num {
+                    return event.as_ptr();
+                }
+
+                let location = event_ref.locationInWindow();
+
+                if event_ref.r#type() == NSEventType::LeftMouseDown {
+                    // Resolve which stacked card (if any) the click landed in, and its
+                    // coordinates within that card's own effect_view.
+                    let Some((queue_index, local_x, local_y)) = hit_test_stack(location) else {
+                        if let Ok(mut press) = CARD_PRESS.lock() {
+                            *press = None;
+                        }
+                        return std::ptr::null_mut();
+                    };
+
+                    // Bottom-left dismiss area: 70x27 zone (two 28x22 buttons + gap)
+                    // X button: x=8..36, Trash button: x=42..70 (effect_view coords)
+                    if local_x < 70.0 && local_y < 27.0 {
+                        log::debug!("[native_toast] mouse down in dismiss zone, card={}", queue_index);
+                        if let Ok(mut press) = CARD_PRESS.lock() {
+                            *press = None;
+                        }
+                        if local_x < 38.0 {
+                            handle_dismiss_keep(queue_index);
+                        } else {
+                            handle_dismiss_delete(queue_index);
+                        }
+                    } else {
+                        log::debug!(
+                            "[native_toast] card press down, card={}, local_x={:.1}, local_y={:.1}",
+                            queue_index,
+                            local_x,
+                            local_y
+                        );
+                        if let Ok(mut press) = CARD_PRESS.lock() {
+                            *press = Some(CardPress {
+                                queue_index,
+                                started_at: Instant::now(),
+                                location,
+                                option_held: event_ref
+                                    .modifierFlags()
+                                    .contains(NSEventModifierFlags::Option),
+                            });
+                        }
+                    }
+                    return std::ptr::null_mut();
+                }
+
+                if event_ref.r#type() == NSEventType::LeftMouseUp {
+                    let press = CARD_PRESS.lock().ok().and_then(|mut p| p.take());
+                    if let Some(press) = press {
+                        classify_card_press(press, location);
+                    }
+                    return std::ptr::null_mut();
+                }
+
+                event.as_ptr()
+            });
+
+            let mask = NSEventMask::LeftMouseDown | NSEventMask::LeftMouseUp;
+            let _monitor: Option<Retained<objc2_foundation::NSObject>> = msg_send_id![
+                NSEvent::class(),
+                addLocalMonitorForEventsMatchingMask: mask.0,
+                handler: &*block
+            ];
+
+            // Keep alive for app lifetime
+            std::mem::forget(_monitor);
+            std::mem::forget(block);
+        }
+    });
+}
+
+/// Classifies a completed press against the card region: beyond
+/// `CARD_PRESS_DRAG_TOLERANCE` of movement cancels it as a drag; held for
+/// `[toast.hold_ms]` or longer snoozes the notification; otherwise it's a
+/// short click, same as before (terminal focus, with an option-click peek
+/// variant that doesn't dismiss).
+fn classify_card_press(press: CardPress, up_location: CGPoint) {
+    let dx = up_location.x - press.location.x;
+    let dy = up_location.y - press.location.y;
+    if (dx * dx + dy * dy).sqrt() > CARD_PRESS_DRAG_TOLERANCE {
+        log::debug!("[native_toast] card press cancelled: moved beyond drag tolerance");
+        return;
+    }
+
+    let hold_ms = config::load_config().toast.hold_ms;
+    if press.started_at.elapsed().as_millis() as u64 >= hold_ms {
+        log::debug!("[native_toast] long press: snoozing notification");
+        snooze_current(press.queue_index);
+    } else if press.option_held {
+        log::debug!("[native_toast] option-click: focus without dismissing");
+        handle_card_peek(press.queue_index);
+    } else {
+        handle_card_click(press.queue_index);
+    }
+}
+
+/// Re-queues the notification at `idx` to the back of the queue and advances
+/// past it, instead of deleting it -- a non-destructive "remind me later"
+/// triggered by a long press on the card.
+fn snooze_current(idx: usize) {
+    let Some(state_mutex) = TOAST_STATE.get() else {
+        return;
+    };
+    let is_front = {
+        let state = match state_mutex.lock() {
+            Ok(s) => s,
+            Err(_) => return,
+        };
+        state.current_index == idx
+    };
+    if is_front {
+        cancel_timer();
+    }
+    let has_remaining = {
+        let mut state = match state_mutex.lock() {
+            Ok(s) => s,
+            Err(_) => return,
+        };
+        if idx >= state.queue.len() {
+            return;
+        }
+        let snoozed = state.queue.remove(idx);
+        if state.current_index > idx {
+            state.current_index -= 1;
+        } else if state.current_index >= state.queue.len() {
+            state.current_index = 0;
+        }
+        state.queue.push(snoozed);
+        !state.queue.is_empty()
+    };
+    if has_remaining {
+        update_and_show();
+    } else {
+        hide();
+    }
+}
+
+/// Removes the card at `idx` from the queue without re-queuing it, shifting
+/// `current_index` to keep pointing at the same logical card, then
+/// re-renders the stack. Used by the non-front-card path of the dismiss and
+/// click handlers below; the front card keeps its existing advance/fade
+/// behavior instead, since that's the one path with a timer and a panel
+/// fade-out to coordinate.
+fn remove_card(idx: usize) {
+    let Some(state_mutex) = TOAST_STATE.get() else {
+        return;
+    };
+    let has_remaining = {
+        let mut state = match state_mutex.lock() {
+            Ok(s) => s,
+            Err(_) => return,
+        };
+        if idx >= state.queue.len() {
+            return;
+        }
+        state.queue.remove(idx);
+        if state.current_index > idx {
+            state.current_index -= 1;
+        } else if state.current_index >= state.queue.len() {
+            state.current_index = 0;
+        }
+        !state.queue.is_empty()
+    };
+    if has_remaining {
+        update_and_show();
+    } else {
+        hide();
+    }
+}
+
+/// Pauses/resumes auto-advance while the pointer hovers the toast, mirroring
+/// the local-monitor hit-testing `install_event_monitor` already uses for
+/// clicks rather than a tracking-area-owning view subclass: a local monitor
+/// tells us when a mouse-moved event lands in our own panel (entered) or one
+/// of the app's other windows (exited), and a global monitor catches the
+/// remaining case of the pointer leaving into another app entirely.
+fn install_hover_monitor() {
+    HOVER_MONITOR_INSTALLED.get_or_init(|| {
+        unsafe {
+            let local_block = RcBlock::new(|event: std::ptr::NonNull<NSEvent>| -> *mut NSEvent {
+                let event_ref = event.as_ref();
+                if let Some(wrapper) = TOAST_PANEL.get() {
+                    let panel = &wrapper.0;
+                    let event_window_num: i64 = msg_send![event_ref, windowNumber];
+                    let panel_window_num: i64 = msg_send![panel, windowNumber];
+                    set_hover(event_window_num == panel_window_num);
+                }
+                event.as_ptr()
+            });
+            let _local_monitor: Option<Retained<objc2_foundation::NSObject>> = msg_send_id![
+                NSEvent::class(),
+                addLocalMonitorForEventsMatchingMask: NSEventMask::MouseMoved.0,
+                handler: &*local_block
+            ];
+            std::mem::forget(_local_monitor);
+            std::mem::forget(local_block);
+
+            let global_block = RcBlock::new(|_event: std::ptr::NonNull<NSEvent>| {
+                set_hover(false);
+            });
+            let _global_monitor: Option<Retained<objc2_foundation::NSObject>> = msg_send_id![
+                NSEvent::class(),
+                addGlobalMonitorForEventsMatchingMask: NSEventMask::MouseMoved.0,
+                handler: &*global_block
+            ];
+            std::mem::forget(_global_monitor);
+            std::mem::forget(global_block);
+        }
+    });
+}
+
+/// Lets the toast's queue be navigated by keyboard while it's on screen,
+/// without the panel ever becoming key: a local monitor catches the keys
+/// while the toast's own window happens to have them (rare, since it's
+/// `becomesKeyOnlyIfNeeded`), and a global monitor catches the common case
+/// of them arriving at whatever window -- a terminal, usually -- actually
+/// has focus. Both funnel into `handle_toast_key`, which is a no-op unless
+/// the toast is currently visible.
+fn install_key_monitor() {
+    KEY_MONITOR_INSTALLED.get_or_init(|| {
+        unsafe {
+            let local_block = RcBlock::new(|event: std::ptr::NonNull<NSEvent>| -> *mut NSEvent {
+                if handle_toast_key(event.as_ref()) {
+                    std::ptr::null_mut()
+                } else {
+                    event.as_ptr()
+                }
+            });
+            let _local_monitor: Option<Retained<objc2_foundation::NSObject>> = msg_send_id![
+                NSEvent::class(),
+                addLocalMonitorForEventsMatchingMask: NSEventMask::KeyDown.0,
+                handler: &*local_block
+            ];
+            std::mem::forget(_local_monitor);
+            std::mem::forget(local_block);
+
+            let global_block = RcBlock::new(|event: std::ptr::NonNull<NSEvent>| {
+                handle_toast_key(event.as_ref());
+            });
+            let _global_monitor: Option<Retained<objc2_foundation::NSObject>> = msg_send_id![
+                NSEvent::class(),
+                addGlobalMonitorForEventsMatchingMask: NSEventMask::KeyDown.0,
+                handler: &*global_block
+            ];
+            std::mem::forget(_global_monitor);
+            std::mem::forget(global_block);
+        }
+    });
+}
+
+/// Matches `event` against the configured `[toast.keys]` bindings and acts
+/// on the first one that applies. Returns whether it consumed the event (so
+/// the local monitor can swallow it instead of forwarding it on).
+fn handle_toast_key(event: &NSEvent) -> bool {
+    let Some(state_mutex) = TOAST_STATE.get() else {
+        return false;
+    };
+    let visible = matches!(state_mutex.lock(), Ok(state) if state.is_visible);
+    if !visible {
+        return false;
+    }
+
+    let pressed = pressed_key_name(event);
+    let keys = config::load_config().toast.keys;
+    if pressed == keys.next || pressed == "down" {
+        cancel_timer();
+        key_advance();
+        true
+    } else if pressed == keys.previous || pressed == "up" {
+        cancel_timer();
+        key_retreat();
+        true
+    } else if pressed == keys.dismiss {
+        let current_index = match state_mutex.lock() {
+            Ok(state) => state.current_index,
+            Err(_) => return false,
+        };
+        handle_dismiss_keep(current_index);
+        true
+    } else if pressed == keys.dismiss_all {
+        cancel_timer();
+        hide();
+        true
+    } else {
+        false
+    }
+}
+
+/// The pressed key as a lowercase character, or `"up"`/`"down"`/`"left"`/
+/// `"right"` for the arrow keys (whose `charactersIgnoringModifiers` is a
+/// private-use-area codepoint, not something a user would put in config).
+fn pressed_key_name(event: &NSEvent) -> String {
+    const ARROW_LEFT: u16 = 123;
+    const ARROW_RIGHT: u16 = 124;
+    const ARROW_DOWN: u16 = 125;
+    const ARROW_UP: u16 = 126;
+
+    match event.keyCode() {
+        ARROW_LEFT => "left".to_string(),
+        ARROW_RIGHT => "right".to_string(),
+        ARROW_DOWN => "down".to_string(),
+        ARROW_UP => "up".to_string(),
+        _ => event
+            .charactersIgnoringModifiers()
+            .map(|s| s.to_string().to_lowercase())
+            .unwrap_or_default(),
+    }
+}
+
+/// Moves to the next queued notification without removing anything from the
+/// queue, mirroring `advance()` but driven by a keypress instead of the
+/// auto-advance timer or a card click.
+fn key_advance() {
+    let Some(state_mutex) = TOAST_STATE.get() else {
+        return;
+    };
+    let has_next = {
+        let mut state = match state_mutex.lock() {
+            Ok(s) => s,
+            Err(_) => return,
+        };
+        let has_next = state.current_index + 1 < state.queue.len();
+        if has_next {
+            state.current_index += 1;
+        }
+        has_next
+    };
+    if has_next {
+        update_and_show();
+    }
+}
+
+/// Moves to the previous queued notification. The queue is append-only
+/// (nothing is removed until dismissed), so this is just `key_advance` in
+/// reverse.
+fn key_retreat() {
+    let Some(state_mutex) = TOAST_STATE.get() else {
+        return;
+    };
+    let moved = {
+        let mut state = match state_mutex.lock() {
+            Ok(s) => s,
+            Err(_) => return,
+        };
+        let moved = state.current_index > 0;
+        if moved {
+            state.current_index -= 1;
+        }
+        moved
+    };
+    if moved {
+        update_and_show();
+    }
+}
+
+fn set_hover(hovering: bool) {
+    let mut current = match HOVERING.lock() {
+        Ok(h) => h,
+        Err(_) => return,
+    };
+    if *current == hovering {
+        return;
+    }
+    *current = hovering;
+    drop(current);
+
+    if hovering {
+        pause_timer_for_hover();
+    } else {
+        resume_timer_after_hover();
+    }
+}
+
+fn pause_timer_for_hover() {
+    let Some(state_mutex) = TOAST_STATE.get() else {
+        return;
+    };
+
+    let remaining = {
+        let state = match state_mutex.lock() {
+            Ok(s) => s,
+            Err(_) => return,
+        };
+        if state.persistent || !state.is_visible {
+            return;
+        }
+        state.timer_started_at.map(|started| {
+            let elapsed_ms = started.elapsed().as_millis() as u64;
+            state.active_duration_ms.saturating_sub(elapsed_ms).max(500)
+        })
+    };
+
+    if let Ok(mut r) = HOVER_REMAINING_MS.lock() {
+        *r = remaining;
+    }
+    cancel_timer();
+    cancel_fade_timer();
+}
+
+fn resume_timer_after_hover() {
+    let remaining = HOVER_REMAINING_MS.lock().ok().and_then(|mut r| r.take());
+    if let Some(ms) = remaining {
+        start_timer(ms);
+    }
+}
+
+// --- Click handlers ---
+
+fn handle_card_click(idx: usize) {
+    let Some(state_mutex) = TOAST_STATE.get() else {
+        return;
+    };
+
+    let (notification, is_front) = {
+        let state = match state_mutex.lock() {
+            Ok(s) => s,
+            Err(_) => return,
+        };
+        (state.queue.get(idx).cloned(), state.current_index == idx)
+    };
+
+    if is_front {
+        cancel_timer();
+    }
+
+    if let Some(n) = notification {
+        // Delete notification from DB (unless force_focus)
+        if !n.force_focus {
+            if let Some(app_handle) = APP_HANDLE.get() {
+                let db_path = config::db_path();
+                if let Ok(conn) = agentoast_shared::db::open_reader(&db_path) {
+                    let _ = agentoast_shared::db::delete_notification(&conn, n.id);
+                    if let Ok(count) = agentoast_shared::db::get_unread_count(&conn) {
+                        let _ = app_handle.emit("notifications:unread-count", count);
+                        crate::watcher::update_tray_icon(app_handle, count);
+                    }
+                }
+            }
+        }
+
+        // Focus terminal
+        if !n.tmux_pane.is_empty() {
+            if let Err(e) = terminal::focus_terminal(&n.tmux_pane, &n.terminal_bundle_id) {
+                log::debug!("[native_toast] focus_terminal failed: {}", e);
+            }
+        }
+    }
+
+    if is_front {
+        let has_next = {
+            let state = match state_mutex.lock() {
+                Ok(s) => s,
+                Err(_) => return,
+            };
+            state.current_index + 1 < state.queue.len()
+        };
+        if has_next {
+            advance();
+        } else {
+            fade_out_and_hide();
+        }
+    } else {
+        remove_card(idx);
+    }
+}
+
+/// Option-click variant of `handle_card_click`: focuses the terminal/tmux
+/// pane for the notification at `idx` but leaves the queue and the toast
+/// itself untouched, so users can triage several notifications in a row
+/// without losing their place or dismissing ones they haven't read yet.
+fn handle_card_peek(idx: usize) {
+    let Some(state_mutex) = TOAST_STATE.get() else {
+        return;
+    };
+
+    let notification = {
+        let state = match state_mutex.lock() {
+            Ok(s) => s,
+            Err(_) => return,
+        };
+        state.queue.get(idx).cloned()
+    };
+
+    if let Some(n) = notification {
+        if !n.tmux_pane.is_empty() {
+            if let Err(e) = terminal::focus_terminal(&n.tmux_pane, &n.terminal_bundle_id) {
+                log::debug!("[native_toast] focus_terminal failed: {}", e);
+            }
+        }
+    }
+}
+
+fn handle_dismiss_keep(idx: usize) {
+    let Some(state_mutex) = TOAST_STATE.get() else {
+        return;
+    };
+    let is_front = {
+        let state = match state_mutex.lock() {
+            Ok(s) => s,
+            Err(_) => return,
+        };
+        state.current_index == idx
+    };
+
+    if is_front {
+        cancel_timer();
+        let has_next = {
+            let state = match state_mutex.lock() {
+                Ok(s) => s,
+                Err(_) => return,
+            };
+            state.current_index + 1 < state.queue.len()
+        };
+        if has_next {
+            advance();
+        } else {
+            fade_out_and_hide();
+        }
+    } else {
+        remove_card(idx);
+    }
+}
+
+fn handle_dismiss_delete(idx: usize) {
+    let Some(state_mutex) = TOAST_STATE.get() else {
+        return;
+    };
+
+    let (notification, is_front) = {
+        let state = match state_mutex.lock() {
+            Ok(s) => s,
+            Err(_) => return,
+        };
+        (state.queue.get(idx).cloned(), state.current_index == idx)
+    };
+
+    if is_front {
+        cancel_timer();
+    }
+
+    if let Some(n) = notification {
+        if !n.force_focus {
+            if let Some(app_handle) = APP_HANDLE.get() {
+                let db_path = config::db_path();
+                if let Ok(conn) = agentoast_shared::db::open_reader(&db_path) {
+                    let _ = agentoast_shared::db::delete_notification(&conn, n.id);
+                    if let Ok(count) = agentoast_shared::db::get_unread_count(&conn) {
+                        let _ = app_handle.emit("notifications:unread-count", count);
+                        crate::watcher::update_tray_icon(app_handle, count);
+                    }
+                }
+            }
+        }
+    }
+
+    if is_front {
+        let has_next = {
+            let state = match state_mutex.lock() {
+                Ok(s) => s,
+                Err(_) => return,
+            };
+            state.current_index + 1 < state.queue.len()
+        };
+        if has_next {
+            advance();
+        } else {
+            fade_out_and_hide();
+        }
+    } else {
+        remove_card(idx);
+    }
+}
+
+/// `ToastBackend::dismiss_keep`/`dismiss_delete` entry points for
+/// `control_socket`: act on whatever card is currently at the front of the
+/// queue, the same card the keyboard's dismiss binding targets.
+fn control_dismiss_keep() {
+    let Some(state_mutex) = TOAST_STATE.get() else {
+        return;
+    };
+    let current_index = match state_mutex.lock() {
+        Ok(state) => state.current_index,
+        Err(_) => return,
+    };
+    handle_dismiss_keep(current_index);
+}
+
+fn control_dismiss_delete() {
+    let Some(state_mutex) = TOAST_STATE.get() else {
+        return;
+    };
+    let current_index = match state_mutex.lock() {
+        Ok(state) => state.current_index,
+        Err(_) => return,
+    };
+    handle_dismiss_delete(current_index);
+}
+
+fn advance() {
+    let Some(state_mutex) = TOAST_STATE.get() else {
+        return;
+    };
+    {
+        let mut state = match state_mutex.lock() {
+            Ok(s) => s,
+            Err(_) => return,
+        };
+        state.current_index += 1;
+    }
+    update_and_show();
+}
+
+/// Fades the panel out while sliding it toward whichever edge `[toast.anchor]`
+/// is pinned to, so the toast visually retreats the way it arrived instead of
+/// just fading in place.
+fn fade_out_and_hide() {
+    let Some(wrapper) = TOAST_PANEL.get() else {
+        return;
+    };
+    let panel = &wrapper.0;
+    cancel_timer();
+
+    const SLIDE_DISTANCE: f64 = 24.0;
+    let anchor = config::load_config().toast.anchor;
+    let dx = match anchor {
+        config::ToastAnchor::TopLeft | config::ToastAnchor::BottomLeft => -SLIDE_DISTANCE,
+        config::ToastAnchor::TopRight | config::ToastAnchor::BottomRight => SLIDE_DISTANCE,
+    };
+    let current_frame = panel.frame();
+    let target_frame = CGRect::new(
+        CGPoint::new(current_frame.origin.x + dx, current_frame.origin.y),
+        current_frame.size,
+    );
+
+    let panel_ptr = Retained::as_ptr(panel) as usize;
+    NSAnimationContext::runAnimationGroup(&RcBlock::new(
+        move |context: std::ptr::NonNull<NSAnimationContext>| {
+            let ctx = unsafe { context.as_ref() };
+            ctx.setDuration(FADE_DURATION);
+            let panel_ref: &NSPanel = unsafe { &*(panel_ptr as *const NSPanel) };
+            let animator: Retained<NSPanel> = unsafe { msg_send_id![panel_ref, animator] };
+            animator.setAlphaValue(0.0);
+            animator.setFrame_display(target_frame, true);
+        },
+    ));
+
+    start_fade_timer();
+}
+
+/// Places `panel` in the configured corner of the configured screen, via
+/// `[toast.anchor]`/`margin_x`/`margin_y`. The menu bar is only deducted on
+/// top anchors and the Dock only on bottom anchors, since each only
+/// encroaches on the edge it's on -- `visibleFrame()` already has both
+/// trimmed out, so we fall back to the raw `frame()` on the edge that isn't
+/// relevant and read the trimmed `visibleFrame()` on the one that is.
+fn position_panel(mtm: MainThreadMarker, panel: &NSPanel, panel_height: f64) {
+    let cfg = config::load_config();
+    let screen = match select_screen(mtm, &cfg.toast.screen) {
+        Some(s) => s,
+        None => return,
+    };
+    let screen_frame = screen.frame();
+    let visible_frame = screen.visibleFrame();
+    let margin_x = cfg.toast.margin_x;
+    let margin_y = cfg.toast.margin_y;
+
+    let x = match cfg.toast.anchor {
+        config::ToastAnchor::TopLeft | config::ToastAnchor::BottomLeft => {
+            screen_frame.origin.x + margin_x
+        }
+        config::ToastAnchor::TopRight | config::ToastAnchor::BottomRight => {
+            screen_frame.origin.x + screen_frame.size.width - PANEL_WIDTH - margin_x
+        }
+    };
+
+    let y = match cfg.toast.anchor {
+        config::ToastAnchor::TopLeft | config::ToastAnchor::TopRight => {
+            visible_frame.origin.y + visible_frame.size.height - panel_height - margin_y
+        }
+        config::ToastAnchor::BottomLeft | config::ToastAnchor::BottomRight => {
+            visible_frame.origin.y + margin_y
+        }
+    };
+
+    panel.setFrame_display(
+        CGRect::new(CGPoint::new(x, y), CGSize::new(PANEL_WIDTH, panel_height)),
+        true,
+    );
+}
+
+/// Resolves a `ScreenPolicy` to the `NSScreen` it names, for multi-monitor
+/// setups. Falls back to `NSScreen::mainScreen` wherever the requested
+/// target can't be found (an out-of-range fixed index, no screen under the
+/// cursor); with no key window yet, `KeyWindow` first tries the screen under
+/// the cursor.
+fn select_screen(mtm: MainThreadMarker, policy: &config::ScreenPolicy) -> Option<Retained<NSScreen>> {
+    match policy {
+        config::ScreenPolicy::Cursor => {
+            screen_containing_point(mtm, NSEvent::mouseLocation()).or_else(|| NSScreen::mainScreen(mtm))
+        }
+        config::ScreenPolicy::KeyWindow => {
+            let app = NSApplication::sharedApplication(mtm);
+            let key_screen = app
+                .keyWindow()
+                .or_else(|| app.mainWindow())
+                .and_then(|w| w.screen());
+            key_screen
+                .or_else(|| screen_containing_point(mtm, NSEvent::mouseLocation()))
+                .or_else(|| NSScreen::mainScreen(mtm))
+        }
+        config::ScreenPolicy::Fixed { index } => {
+            let screens = NSScreen::screens(mtm);
+            if *index < screens.count() {
+                Some(screens.objectAtIndex(*index))
+            } else {
+                NSScreen::mainScreen(mtm)
+            }
+        }
+    }
+}
+
+/// The screen whose frame contains `point` (in global/flipped-from-bottom
+/// screen coordinates, as returned by `NSEvent::mouseLocation`), if any.
+fn screen_containing_point(mtm: MainThreadMarker, point: CGPoint) -> Option<Retained<NSScreen>> {
+    let screens = NSScreen::screens(mtm);
+    for i in 0..screens.count() {
+        let screen = screens.objectAtIndex(i);
+        let frame = screen.frame();
+        let within_x = point.x >= frame.origin.x && point.x <= frame.origin.x + frame.size.width;
+        let within_y = point.y >= frame.origin.y && point.y <= frame.origin.y + frame.size.height;
+        if within_x && within_y {
+            return Some(screen);
+        }
+    }
+    None
+}
+
+// --- Timer management ---
+
+fn start_timer(duration_ms: u64) {
+    cancel_timer();
+    let interval = duration_ms as f64 / 1000.0;
+    let block = RcBlock::new(move |_timer: std::ptr::NonNull<NSTimer>| {
+        advance_or_hide();
+    });
+    let timer =
+        unsafe { NSTimer::scheduledTimerWithTimeInterval_repeats_block(interval, false, &block) };
+    if let Ok(mut t) = TOAST_TIMER.lock() {
+        *t = Some(SendSyncWrapper(timer));
+    }
+
+    if let Some(state_mutex) = TOAST_STATE.get() {
+        if let Ok(mut state) = state_mutex.lock() {
+            state.timer_started_at = Some(std::time::Instant::now());
+            state.active_duration_ms = duration_ms;
+        }
+    }
+}
+
+fn cancel_timer() {
+    if let Ok(mut t) = TOAST_TIMER.lock() {
+        if let Some(wrapper) = t.take() {
+            wrapper.0.invalidate();
+        }
+    }
+}
+
+fn start_fade_timer() {
+    cancel_fade_timer();
+    let fade_ms = (FADE_DURATION * 1000.0) as u64 + 50;
+    let interval = fade_ms as f64 / 1000.0;
+    let block = RcBlock::new(move |_timer: std::ptr::NonNull<NSTimer>| {
+        hide();
+    });
+    let timer =
+        unsafe { NSTimer::scheduledTimerWithTimeInterval_repeats_block(interval, false, &block) };
+    if let Ok(mut t) = FADE_TIMER.lock() {
+        *t = Some(SendSyncWrapper(timer));
+    }
+}
+
+fn cancel_fade_timer() {
+    if let Ok(mut t) = FADE_TIMER.lock() {
+        if let Some(wrapper) = t.take() {
+            wrapper.0.invalidate();
+        }
+    }
+}
+
+fn advance_or_hide() {
+    let Some(state_mutex) = TOAST_STATE.get() else {
+        return;
+    };
+    let has_next = {
+        let state = match state_mutex.lock() {
+            Ok(s) => s,
+            Err(_) => return,
+        };
+        state.current_index + 1 < state.queue.len()
+    };
+
+    if has_next {
+        advance();
+    } else {
+        fade_out_and_hide();
+    }
+}
@@ -0,0 +1,62 @@
+//! Native (non-webview) toast rendering. `shared` holds everything that
+//! doesn't touch a window system -- the notification queue, layout geometry,
+//! word-wrap measurement and color theming -- so each platform's
+//! `ToastBackend` only has to implement window creation, drawing and input.
+
+mod control_socket;
+mod shared;
+
+#[cfg(target_os = "macos")]
+mod macos;
+#[cfg(target_os = "linux")]
+mod wayland;
+
+#[cfg(target_os = "macos")]
+use macos::MacosBackend as ActiveBackend;
+#[cfg(target_os = "linux")]
+use wayland::WaylandBackend as ActiveBackend;
+
+use agentoast_shared::models::Notification;
+
+/// What a toast window-system integration must provide. `show_notifications`
+/// replaces or merges into the currently displayed queue (same rule on every
+/// backend, via `shared::ToastState::merge`); `hide` tears the surface down;
+/// `init` does one-time setup (panel/surface + event-monitor creation) and
+/// must run before any of the other methods are called. `dismiss_keep`/`dismiss_delete`/
+/// `advance` mirror the per-card actions a click or keypress triggers, so
+/// `control_socket` can drive the same queue the window-system input handlers
+/// do.
+pub(crate) trait ToastBackend {
+    fn init(&self, app_handle: &tauri::AppHandle) -> Result<(), String>;
+    fn show_notifications(&self, notifications: Vec<Notification>);
+    fn hide(&self);
+    fn dismiss_keep(&self);
+    fn dismiss_delete(&self);
+    fn advance(&self);
+}
+
+pub fn init(app_handle: &tauri::AppHandle) -> Result<(), String> {
+    ActiveBackend.init(app_handle)?;
+    control_socket::init(app_handle);
+    Ok(())
+}
+
+pub fn show_notifications(notifications: Vec<Notification>) {
+    ActiveBackend.show_notifications(notifications);
+}
+
+pub fn hide() {
+    ActiveBackend.hide();
+}
+
+pub(crate) fn dismiss_keep() {
+    ActiveBackend.dismiss_keep();
+}
+
+pub(crate) fn dismiss_delete() {
+    ActiveBackend.dismiss_delete();
+}
+
+pub(crate) fn advance() {
+    ActiveBackend.advance();
+}
@@ -0,0 +1,625 @@
+//! Platform-independent toast pieces: queue/state management, layout
+//! geometry, greedy word-wrap line counting, color theming, and
+//! relative-time formatting. A `ToastBackend` owns window creation, drawing
+//! and input for its platform; everything that doesn't touch a window
+//! system lives here instead, so a new backend only has to wire up the
+//! platform-specific half.
+
+use std::time::Instant;
+
+use agentoast_shared::models::Notification;
+
+pub(crate) const PANEL_WIDTH: f64 = 380.0;
+pub(crate) const PADDING: f64 = 8.0;
+pub(crate) const CORNER_RADIUS: f64 = 12.0;
+pub(crate) const FADE_DURATION: f64 = 0.3;
+
+// Shared layout constants (used by both compute_panel_height and each backend's draw pass)
+pub(crate) const TOP_MARGIN: f64 = 12.0;
+pub(crate) const LINE1_HEIGHT: f64 = 18.0;
+pub(crate) const META_HEIGHT: f64 = 16.0;
+pub(crate) const BODY_LINE_HEIGHT: f64 = 14.0;
+pub(crate) const LINE_GAP: f64 = 6.0;
+pub(crate) const BOTTOM_GAP: f64 = 10.0;
+pub(crate) const BOTTOM_SECTION_H: f64 = 27.0;
+pub(crate) const BOTTOM_MARGIN: f64 = 5.0;
+/// Body text never grows the panel past this many lines; a longer message
+/// is truncated at the last line instead.
+pub(crate) const MAX_BODY_LINES: usize = 4;
+pub(crate) const BODY_X: f64 = 12.0;
+pub(crate) const BODY_WIDTH: f64 = PANEL_WIDTH - PADDING * 2.0 - BODY_X - 12.0;
+/// Vertical space between stacked cards (and between the last card and the
+/// overflow pill) in stacking mode.
+pub(crate) const STACK_GAP: f64 = 8.0;
+/// Height of the "+k more" overflow pill stacking mode shows in place of the
+/// last card once the queue has more items than `[toast.stack_size]` fits.
+pub(crate) const STACK_OVERFLOW_PILL_HEIGHT: f64 = 26.0;
+
+pub(crate) struct ToastState {
+    pub(crate) queue: Vec<Notification>,
+    pub(crate) current_index: usize,
+    pub(crate) is_visible: bool,
+    pub(crate) duration_ms: u64,
+    pub(crate) persistent: bool,
+    /// When the currently-scheduled auto-advance timer was started, paired
+    /// with `active_duration_ms` so a hover/pause can compute exactly how
+    /// much time was left and resume with that instead of the full duration.
+    pub(crate) timer_started_at: Option<Instant>,
+    pub(crate) active_duration_ms: u64,
+}
+
+impl ToastState {
+    pub(crate) fn new(duration_ms: u64, persistent: bool) -> Self {
+        ToastState {
+            queue: Vec::new(),
+            current_index: 0,
+            is_visible: false,
+            duration_ms,
+            persistent,
+            timer_started_at: None,
+            active_duration_ms: duration_ms,
+        }
+    }
+
+    /// Replaces the queue with a fresh one (LIFO: newest first), or merges
+    /// `notifications` into the one already on screen, deduping anything
+    /// still queued for the same `tmux_pane`. Every backend's
+    /// `show_notifications` needs exactly this rule.
+    pub(crate) fn merge(&mut self, notifications: Vec<Notification>) {
+        if self.queue.is_empty() || !self.is_visible {
+            let mut reversed = notifications;
+            reversed.reverse();
+            self.queue = reversed;
+            self.current_index = 0;
+            self.is_visible = true;
+            return;
+        }
+
+        let current_idx = self.current_index;
+        let remaining: Vec<Notification> = self.queue[current_idx..]
+            .iter()
+            .filter(|q| {
+                !notifications
+                    .iter()
+                    .any(|n| !n.tmux_pane.is_empty() && q.tmux_pane == n.tmux_pane)
+            })
+            .cloned()
+            .collect();
+
+        let mut new_items: Vec<Notification> = notifications;
+        new_items.reverse();
+        new_items.extend(remaining);
+
+        self.queue = new_items;
+        self.current_index = 0;
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.is_visible = false;
+        self.queue.clear();
+        self.current_index = 0;
+    }
+}
+
+pub(crate) fn compute_panel_height(has_meta: bool, body_lines: usize) -> f64 {
+    let meta_section = if has_meta { LINE_GAP + META_HEIGHT } else { 0.0 };
+    let body_section = if body_lines > 0 {
+        LINE_GAP + body_lines as f64 * BODY_LINE_HEIGHT
+    } else {
+        0.0
+    };
+    let effect_h = TOP_MARGIN + LINE1_HEIGHT + meta_section + body_section
+        + BOTTOM_GAP + BOTTOM_SECTION_H + BOTTOM_MARGIN;
+    effect_h + PADDING * 2.0
+}
+
+/// Whether `c` belongs to a CJK block. These scripts aren't space-separated,
+/// so every character is its own wrap candidate rather than only the
+/// whitespace boundaries Latin text gives us for free.
+pub(crate) fn is_cjk(c: char) -> bool {
+    matches!(c as u32,
+        0x3040..=0x30FF | 0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xAC00..=0xD7A3)
+}
+
+/// Splits `text` into greedy-wrap tokens: a run of non-whitespace, non-CJK
+/// characters is one token (wrapping never happens mid-token unless it
+/// alone overflows the line); each CJK character is its own token.
+pub(crate) fn tokenize_for_wrap(text: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !current.is_empty() {
+                tokens.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        if is_cjk(c) {
+            if !current.is_empty() {
+                tokens.push(std::mem::take(&mut current));
+            }
+            tokens.push(c.to_string());
+            continue;
+        }
+        current.push(c);
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
+/// Hard-breaks a single token wider than `max_width` into width-fitting
+/// pieces, character by character, for the rare case of a long unbroken run
+/// (e.g. a URL) with no whitespace/CJK candidate to wrap at. `measure` is the
+/// backend's text-measurement pass (`NSFont`/`sizeToFit` on macOS, a Pango
+/// layout on Wayland) so this stays platform-independent.
+pub(crate) fn hard_break(token: &str, max_width: f64, measure: &dyn Fn(&str) -> f64) -> Vec<String> {
+    let mut pieces = Vec::new();
+    let mut current = String::new();
+    for c in token.chars() {
+        let candidate = format!("{current}{c}");
+        if !current.is_empty() && measure(&candidate) > max_width {
+            pieces.push(std::mem::take(&mut current));
+        }
+        current.push(c);
+    }
+    if !current.is_empty() {
+        pieces.push(current);
+    }
+    pieces
+}
+
+/// Walks `text` accumulating per-token advance widths (via `measure`) to
+/// count how many lines it actually wraps to at `max_width`, clamped to
+/// `max_lines`. An explicit `\n` always forces a break; trailing whitespace
+/// is dropped by `tokenize_for_wrap` so it never causes a premature one.
+pub(crate) fn wrapped_line_count(
+    text: &str,
+    max_width: f64,
+    max_lines: usize,
+    measure: &dyn Fn(&str) -> f64,
+) -> usize {
+    if text.trim().is_empty() {
+        return 0;
+    }
+
+    let mut lines: Vec<String> = Vec::new();
+
+    for paragraph in text.split('\n') {
+        let tokens = tokenize_for_wrap(paragraph);
+        if tokens.is_empty() {
+            lines.push(String::new());
+        } else {
+            let mut current = String::new();
+            for token in tokens {
+                if current.is_empty() {
+                    if measure(&token) > max_width {
+                        lines.extend(hard_break(&token, max_width, measure));
+                    } else {
+                        current = token;
+                    }
+                    continue;
+                }
+
+                let candidate = format!("{current} {token}");
+                if measure(&candidate) <= max_width {
+                    current = candidate;
+                } else {
+                    lines.push(std::mem::take(&mut current));
+                    if measure(&token) > max_width {
+                        lines.extend(hard_break(&token, max_width, measure));
+                    } else {
+                        current = token;
+                    }
+                }
+            }
+            if !current.is_empty() {
+                lines.push(current);
+            }
+        }
+
+        if lines.len() >= max_lines {
+            break;
+        }
+    }
+
+    lines.len().clamp(1, max_lines)
+}
+
+// --- Color definitions ---
+
+pub(crate) struct ToastColors {
+    pub(crate) bg: (f64, f64, f64, f64),
+    pub(crate) border: (f64, f64, f64, f64),
+    pub(crate) focus_bg: (f64, f64, f64, f64),
+    pub(crate) focus_border: (f64, f64, f64, f64),
+    pub(crate) text_secondary: (f64, f64, f64, f64),
+    pub(crate) text_muted: (f64, f64, f64, f64),
+    pub(crate) badge_stop_bg: (f64, f64, f64, f64),
+    pub(crate) badge_stop_text: (f64, f64, f64, f64),
+    pub(crate) badge_notif_bg: (f64, f64, f64, f64),
+    pub(crate) badge_notif_text: (f64, f64, f64, f64),
+    pub(crate) badge_red_bg: (f64, f64, f64, f64),
+    pub(crate) badge_red_text: (f64, f64, f64, f64),
+    pub(crate) badge_gray_bg: (f64, f64, f64, f64),
+    pub(crate) badge_gray_text: (f64, f64, f64, f64),
+    pub(crate) focus_badge_bg: (f64, f64, f64, f64),
+    pub(crate) focus_badge_text: (f64, f64, f64, f64),
+}
+
+/// Builds the color palette for `is_dark`. Each backend decides for itself
+/// how to detect dark mode (`NSApplication.effectiveAppearance` on macOS, the
+/// desktop's color-scheme preference on Wayland) and passes the answer in.
+pub(crate) fn colors(is_dark: bool) -> ToastColors {
+    if is_dark {
+        ToastColors {
+            bg: (0.173, 0.173, 0.18, 0.95),
+            border: (1.0, 1.0, 1.0, 0.10),
+            focus_bg: (0.216, 0.157, 0.314, 0.95),
+            focus_border: (0.545, 0.361, 0.965, 0.40),
+            text_secondary: (1.0, 1.0, 1.0, 0.70),
+            text_muted: (1.0, 1.0, 1.0, 0.40),
+            badge_stop_bg: (0.133, 0.773, 0.369, 0.20),
+            badge_stop_text: (0.290, 0.855, 0.502, 1.0),
+            badge_notif_bg: (0.231, 0.510, 0.965, 0.20),
+            badge_notif_text: (0.376, 0.647, 0.980, 1.0),
+            badge_red_bg: (0.961, 0.259, 0.259, 0.20),
+            badge_red_text: (0.973, 0.443, 0.443, 1.0),
+            badge_gray_bg: (1.0, 1.0, 1.0, 0.10),
+            badge_gray_text: (1.0, 1.0, 1.0, 0.50),
+            focus_badge_bg: (0.545, 0.361, 0.965, 0.25),
+            focus_badge_text: (0.655, 0.545, 0.980, 1.0),
+        }
+    } else {
+        ToastColors {
+            bg: (1.0, 1.0, 1.0, 0.95),
+            border: (0.0, 0.0, 0.0, 0.10),
+            focus_bg: (0.929, 0.914, 0.996, 0.95),
+            focus_border: (0.545, 0.361, 0.965, 0.35),
+            text_secondary: (0.0, 0.0, 0.0, 0.70),
+            text_muted: (0.0, 0.0, 0.0, 0.40),
+            badge_stop_bg: (0.133, 0.773, 0.369, 0.15),
+            badge_stop_text: (0.086, 0.639, 0.290, 1.0),
+            badge_notif_bg: (0.231, 0.510, 0.965, 0.15),
+            badge_notif_text: (0.145, 0.388, 0.929, 1.0),
+            badge_red_bg: (0.961, 0.259, 0.259, 0.15),
+            badge_red_text: (0.937, 0.267, 0.267, 1.0),
+            badge_gray_bg: (0.0, 0.0, 0.0, 0.10),
+            badge_gray_text: (0.0, 0.0, 0.0, 0.50),
+            focus_badge_bg: (0.545, 0.361, 0.965, 0.15),
+            focus_badge_text: (0.486, 0.227, 0.929, 1.0),
+        }
+    }
+}
+
+pub(crate) type ColorTuple = (f64, f64, f64, f64);
+
+pub(crate) fn badge_colors(badge_color: &str, colors: &ToastColors) -> (ColorTuple, ColorTuple) {
+    match badge_color {
+        "green" => (colors.badge_stop_bg, colors.badge_stop_text),
+        "blue" => (colors.badge_notif_bg, colors.badge_notif_text),
+        "red" => (colors.badge_red_bg, colors.badge_red_text),
+        _ => (colors.badge_gray_bg, colors.badge_gray_text),
+    }
+}
+
+pub(crate) fn format_relative_time(created_at: &str) -> String {
+    // Parse ISO 8601: "2025-01-01T12:00:00.000Z"
+    // No chrono dependency (binary size optimization), manual parse
+    let parts: Vec<&str> = created_at.split('T').collect();
+    if parts.len() != 2 {
+        return String::new();
+    }
+    let date_parts: Vec<u32> = parts[0].split('-').filter_map(|s| s.parse().ok()).collect();
+    let time_str = parts[1].trim_end_matches('Z');
+    let time_parts: Vec<&str> = time_str.split(':').collect();
+    if date_parts.len() != 3 || time_parts.len() < 2 {
+        return String::new();
+    }
+
+    let (year, month, day) = (date_parts[0], date_parts[1], date_parts[2]);
+    let hour: u32 = time_parts[0].parse().unwrap_or(0);
+    let min: u32 = time_parts[1].parse().unwrap_or(0);
+    let sec: u32 = time_parts[2]
+        .split('.')
+        .next()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(0);
+
+    let created_days = days_from_civil(year as i64, month as i64, day as i64);
+    let created_secs =
+        created_days * 86400 + hour as i64 * 3600 + min as i64 * 60 + sec as i64;
+
+    // Get current UTC time via SystemTime (unix epoch based)
+    let now_secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64;
+
+    let diff = now_secs - created_secs;
+    if diff < 60 {
+        "just now".to_string()
+    } else if diff < 3600 {
+        format!("{}m ago", diff / 60)
+    } else if diff < 86400 {
+        format!("{}h ago", diff / 3600)
+    } else if diff < 86400 * 7 {
+        format!("{}d ago", diff / 86400)
+    } else if diff < 86400 * 30 {
+        format!("{}w ago", diff / (86400 * 7))
+    } else {
+        format!("{}mo ago", diff / (86400 * 30))
+    }
+}
+
+/// Days since the Unix epoch (1970-01-01) for the proleptic Gregorian date
+/// `y`-`m`-`d`, via Howard Hinnant's `days_from_civil` -- exact (correct
+/// century leap rule) where the old `year*365 + year/4` approximation this
+/// replaced would drift near month/year boundaries.
+fn days_from_civil(y: i64, m: i64, d: i64) -> i64 {
+    let y = y - (m <= 2) as i64;
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    /// Monospace stand-in for a backend's real text measurement: one unit of
+    /// width per character, so wrap-point assertions don't depend on any
+    /// platform font metrics.
+    fn char_width(s: &str) -> f64 {
+        s.chars().count() as f64
+    }
+
+    fn notification(id: i64, tmux_pane: &str) -> Notification {
+        Notification {
+            id,
+            title: format!("title-{id}"),
+            body: String::new(),
+            color: "gray".to_string(),
+            icon: String::new(),
+            group_name: String::new(),
+            metadata: HashMap::new(),
+            tmux_pane: tmux_pane.to_string(),
+            terminal_bundle_id: String::new(),
+            force_focus: false,
+            is_read: false,
+            created_at: "2025-01-01T00:00:00.000Z".to_string(),
+            coalesce_count: 1,
+            remind_at: None,
+        }
+    }
+
+    #[test]
+    fn tokenize_splits_on_whitespace() {
+        let tokens = tokenize_for_wrap("hello world  foo");
+        assert_eq!(tokens, vec!["hello", "world", "foo"]);
+    }
+
+    #[test]
+    fn tokenize_gives_each_cjk_char_its_own_token() {
+        let tokens = tokenize_for_wrap("hi\u{3042}\u{3044}bye");
+        assert_eq!(tokens, vec!["hi", "\u{3042}", "\u{3044}", "bye"]);
+    }
+
+    #[test]
+    fn tokenize_empty_string_yields_no_tokens() {
+        assert!(tokenize_for_wrap("").is_empty());
+        assert!(tokenize_for_wrap("   ").is_empty());
+    }
+
+    #[test]
+    fn hard_break_splits_long_token_at_max_width() {
+        let pieces = hard_break("abcdefghij", 4.0, &char_width);
+        assert_eq!(pieces, vec!["abcd", "efgh", "ij"]);
+    }
+
+    #[test]
+    fn hard_break_short_token_is_one_piece() {
+        let pieces = hard_break("ab", 4.0, &char_width);
+        assert_eq!(pieces, vec!["ab"]);
+    }
+
+    #[test]
+    fn wrapped_line_count_empty_text_is_zero() {
+        assert_eq!(wrapped_line_count("   ", 20.0, 4, &char_width), 0);
+    }
+
+    #[test]
+    fn wrapped_line_count_fits_on_one_line() {
+        assert_eq!(wrapped_line_count("hello world", 20.0, 4, &char_width), 1);
+    }
+
+    #[test]
+    fn wrapped_line_count_wraps_at_max_width() {
+        // "aaaa bbbb cccc" at width 9 -> "aaaa bbbb" / "cccc"
+        assert_eq!(wrapped_line_count("aaaa bbbb cccc", 9.0, 4, &char_width), 2);
+    }
+
+    #[test]
+    fn wrapped_line_count_explicit_newline_forces_break() {
+        assert_eq!(wrapped_line_count("foo\nbar", 20.0, 4, &char_width), 2);
+    }
+
+    #[test]
+    fn wrapped_line_count_clamps_to_max_lines() {
+        let text = "one two three four five six seven eight";
+        assert_eq!(wrapped_line_count(text, 4.0, 2, &char_width), 2);
+    }
+
+    #[test]
+    fn wrapped_line_count_hard_breaks_unbroken_long_token() {
+        // No whitespace at all, wider than max_width -> hard_break kicks in.
+        assert_eq!(wrapped_line_count("abcdefgh", 4.0, 4, &char_width), 2);
+    }
+
+    #[test]
+    fn days_from_civil_unix_epoch_is_zero() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+    }
+
+    #[test]
+    fn days_from_civil_known_date() {
+        // 2024-01-01 is 19723 days after the epoch.
+        assert_eq!(days_from_civil(2024, 1, 1), 19723);
+    }
+
+    #[test]
+    fn days_from_civil_handles_leap_year_boundary() {
+        // 2024 is a leap year: Feb 29 exists, and Mar 1 is one day after it.
+        let feb29 = days_from_civil(2024, 2, 29);
+        let mar1 = days_from_civil(2024, 3, 1);
+        assert_eq!(mar1 - feb29, 1);
+    }
+
+    #[test]
+    fn days_from_civil_handles_century_non_leap_year() {
+        // 1900 is not a leap year (divisible by 100, not 400): Feb 28 -> Mar 1 is 1 day.
+        let feb28 = days_from_civil(1900, 2, 28);
+        let mar1 = days_from_civil(1900, 3, 1);
+        assert_eq!(mar1 - feb28, 1);
+    }
+
+    #[test]
+    fn format_relative_time_malformed_input_is_empty() {
+        assert_eq!(format_relative_time("not-a-date"), "");
+        assert_eq!(format_relative_time("2025-01-01"), "");
+        assert_eq!(format_relative_time(""), "");
+    }
+
+    #[test]
+    fn format_relative_time_just_now() {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let created = iso8601_from_unix_secs(now - 5);
+        assert_eq!(format_relative_time(&created), "just now");
+    }
+
+    #[test]
+    fn format_relative_time_minutes_ago() {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let created = iso8601_from_unix_secs(now - 300);
+        assert_eq!(format_relative_time(&created), "5m ago");
+    }
+
+    #[test]
+    fn format_relative_time_hours_ago() {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let created = iso8601_from_unix_secs(now - 3 * 3600);
+        assert_eq!(format_relative_time(&created), "3h ago");
+    }
+
+    /// Formats a Unix timestamp as the `"YYYY-MM-DDTHH:MM:SS.000Z"` shape
+    /// `format_relative_time` parses, via the same `days_from_civil`
+    /// correspondence (civil-from-days), so these tests don't need a chrono
+    /// dependency either.
+    fn iso8601_from_unix_secs(secs: u64) -> String {
+        let days = secs as i64 / 86400;
+        let time_of_day = secs as i64 % 86400;
+        let (y, m, d) = civil_from_days(days);
+        let hour = time_of_day / 3600;
+        let min = (time_of_day % 3600) / 60;
+        let sec = time_of_day % 60;
+        format!("{y:04}-{m:02}-{d:02}T{hour:02}:{min:02}:{sec:02}.000Z")
+    }
+
+    /// Inverse of `days_from_civil` (Howard Hinnant's `civil_from_days`), used
+    /// only by the test helper above to turn a Unix timestamp back into a
+    /// calendar date.
+    fn civil_from_days(z: i64) -> (i64, i64, i64) {
+        let z = z + 719468;
+        let era = if z >= 0 { z } else { z - 146096 } / 146097;
+        let doe = z - era * 146097;
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let y = yoe + era * 400;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let d = doy - (153 * mp + 2) / 5 + 1;
+        let m = if mp < 10 { mp + 3 } else { mp - 9 };
+        (y + (m <= 2) as i64, m, d)
+    }
+
+    #[test]
+    fn merge_into_empty_state_shows_newest_first() {
+        let mut state = ToastState::new(5000, false);
+        state.merge(vec![notification(1, "a"), notification(2, "b")]);
+        assert!(state.is_visible);
+        assert_eq!(state.current_index, 0);
+        assert_eq!(state.queue.iter().map(|n| n.id).collect::<Vec<_>>(), vec![2, 1]);
+    }
+
+    #[test]
+    fn merge_replaces_queue_when_not_visible() {
+        let mut state = ToastState::new(5000, false);
+        state.merge(vec![notification(1, "a")]);
+        state.is_visible = false;
+        state.merge(vec![notification(2, "b")]);
+        assert_eq!(state.queue.iter().map(|n| n.id).collect::<Vec<_>>(), vec![2]);
+    }
+
+    #[test]
+    fn merge_dedupes_queued_notification_sharing_tmux_pane() {
+        let mut state = ToastState::new(5000, false);
+        state.merge(vec![notification(1, "pane-a"), notification(2, "pane-b")]);
+        // New notification for "pane-a" replaces the still-queued one sharing
+        // that pane, instead of stacking both.
+        state.merge(vec![notification(3, "pane-a")]);
+        let ids: Vec<i64> = state.queue.iter().map(|n| n.id).collect();
+        assert_eq!(ids, vec![3, 2]);
+    }
+
+    #[test]
+    fn merge_keeps_notifications_with_empty_tmux_pane_distinct() {
+        let mut state = ToastState::new(5000, false);
+        state.merge(vec![notification(1, ""), notification(2, "")]);
+        state.merge(vec![notification(3, "")]);
+        // An empty tmux_pane never matches another empty one, so nothing
+        // already queued gets deduped away.
+        let ids: Vec<i64> = state.queue.iter().map(|n| n.id).collect();
+        assert_eq!(ids, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn merge_drops_already_shown_notifications_before_current_index() {
+        let mut state = ToastState::new(5000, false);
+        state.merge(vec![
+            notification(1, "a"),
+            notification(2, "b"),
+            notification(3, "c"),
+        ]);
+        // Simulate having advanced past the first card.
+        state.current_index = 1;
+        state.merge(vec![notification(4, "d")]);
+        let ids: Vec<i64> = state.queue.iter().map(|n| n.id).collect();
+        // Only remaining[current_index..] (ids 2, 1) survive alongside the
+        // new batch; id 3 (already shown, before current_index) is dropped.
+        assert_eq!(ids, vec![4, 2, 1]);
+        assert_eq!(state.current_index, 0);
+    }
+
+    #[test]
+    fn reset_clears_queue_and_visibility() {
+        let mut state = ToastState::new(5000, false);
+        state.merge(vec![notification(1, "a")]);
+        state.current_index = 0;
+        state.reset();
+        assert!(!state.is_visible);
+        assert!(state.queue.is_empty());
+        assert_eq!(state.current_index, 0);
+    }
+}
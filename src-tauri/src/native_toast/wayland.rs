@@ -0,0 +1,686 @@
+//! Linux/Wayland toast backend: the direct analog of `macos`'s borderless,
+//! non-activating `NSPanel` is a `zwlr_layer_shell_v1` overlay surface
+//! anchored to a screen corner (per `[toast].anchor`) with
+//! `KeyboardInteractivity::None`, so it renders above normal windows without
+//! ever taking keyboard focus. Surface/buffer/seat
+//! plumbing goes through `smithay-client-toolkit`; the panel itself is drawn
+//! with `cairo`/`pangocairo` onto the `wl_shm` buffer it hands back.
+//!
+//! Scope for this first cut: background/border, icon-less text content
+//! (repo/time/tmux pane/body via `shared`'s layout and wrap logic; the badge
+//! pill and other metadata entries are not drawn) and a
+//! single click-anywhere action that focuses the next queued notification or
+//! dismisses. Hover-pause and the per-button dismiss zones `macos` has are
+//! left for a follow-up — they need per-pixel hit-testing against a pointer
+//! position we don't yet track across frames. Terminal-focus-on-click also
+//! stays macOS-only for now: `crate::terminal` has no Linux implementation.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+use agentoast_shared::config;
+use agentoast_shared::models::Notification;
+use cairo::{Context as CairoContext, Format, ImageSurface};
+use pangocairo::functions::{create_layout, show_layout};
+use smithay_client_toolkit::compositor::{CompositorHandler, CompositorState};
+use smithay_client_toolkit::delegate_compositor;
+use smithay_client_toolkit::delegate_layer;
+use smithay_client_toolkit::delegate_output;
+use smithay_client_toolkit::delegate_pointer;
+use smithay_client_toolkit::delegate_registry;
+use smithay_client_toolkit::delegate_seat;
+use smithay_client_toolkit::delegate_shm;
+use smithay_client_toolkit::output::{OutputHandler, OutputState};
+use smithay_client_toolkit::registry::{ProvidesRegistryState, RegistryState};
+use smithay_client_toolkit::registry_handlers;
+use smithay_client_toolkit::seat::pointer::{PointerEvent, PointerEventKind, PointerHandler};
+use smithay_client_toolkit::seat::{Capability, SeatHandler, SeatState};
+use smithay_client_toolkit::shell::wlr_layer::{
+    Anchor, KeyboardInteractivity, Layer, LayerShell, LayerShellHandler, LayerSurface,
+    LayerSurfaceConfigure,
+};
+use smithay_client_toolkit::shell::WaylandSurface;
+use smithay_client_toolkit::shm::slot::SlotPool;
+use smithay_client_toolkit::shm::{Shm, ShmHandler};
+use wayland_client::globals::registry_queue_init;
+use wayland_client::protocol::{wl_output, wl_pointer, wl_seat, wl_surface};
+use wayland_client::{Connection, QueueHandle};
+
+use super::shared::{self, ToastState, BODY_WIDTH, MAX_BODY_LINES, PANEL_WIDTH};
+use super::ToastBackend;
+
+static TOAST_STATE: OnceLock<Mutex<ToastState>> = OnceLock::new();
+static COMMAND_TX: OnceLock<std::sync::mpsc::Sender<Command>> = OnceLock::new();
+/// Bumped on every `show_notifications`/dismiss so a previously-scheduled
+/// auto-advance timer thread can tell it's stale and exit quietly instead of
+/// firing on a queue it no longer owns -- the thread-based equivalent of
+/// `NSTimer::invalidate` in the macOS backend.
+static TIMER_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+enum Command {
+    Render,
+    Hide,
+}
+
+/// Overlay-surface implementation of [`ToastBackend`] for wlr-layer-shell
+/// compositors (sway, Hyprland, river, ...).
+pub(crate) struct WaylandBackend;
+
+impl ToastBackend for WaylandBackend {
+    fn init(&self, app_handle: &tauri::AppHandle) -> Result<(), String> {
+        init(app_handle)
+    }
+
+    fn show_notifications(&self, notifications: Vec<Notification>) {
+        show_notifications(notifications)
+    }
+
+    fn hide(&self) {
+        hide()
+    }
+
+    fn dismiss_keep(&self) {
+        advance_or_hide()
+    }
+
+    fn dismiss_delete(&self) {
+        dismiss_delete()
+    }
+
+    fn advance(&self) {
+        advance_or_hide()
+    }
+}
+
+fn init(_app_handle: &tauri::AppHandle) -> Result<(), String> {
+    let cfg = config::load_config();
+    let _ = TOAST_STATE.set(Mutex::new(ToastState::new(
+        cfg.toast.duration_ms,
+        cfg.toast.persistent,
+    )));
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    let _ = COMMAND_TX.set(tx);
+
+    // The Wayland event queue must be driven from a dedicated thread: it
+    // owns its own blocking dispatch loop for the lifetime of the app,
+    // separate from Tauri's main event loop.
+    std::thread::Builder::new()
+        .name("native-toast-wayland".into())
+        .spawn(move || run_compositor_thread(rx))
+        .map_err(|e| format!("failed to spawn Wayland toast thread: {e}"))?;
+
+    log::info!("[native_toast] wayland init complete");
+    Ok(())
+}
+
+fn show_notifications(notifications: Vec<Notification>) {
+    if notifications.is_empty() {
+        return;
+    }
+    let Some(state_mutex) = TOAST_STATE.get() else {
+        return;
+    };
+    TIMER_GENERATION.fetch_add(1, Ordering::SeqCst);
+
+    let mut state = match state_mutex.lock() {
+        Ok(s) => s,
+        Err(e) => {
+            log::error!("[native_toast] failed to lock wayland state: {e}");
+            return;
+        }
+    };
+    state.merge(notifications);
+    drop(state);
+
+    send_command(Command::Render);
+}
+
+fn hide() {
+    let Some(state_mutex) = TOAST_STATE.get() else {
+        return;
+    };
+    TIMER_GENERATION.fetch_add(1, Ordering::SeqCst);
+    if let Ok(mut state) = state_mutex.lock() {
+        state.reset();
+    }
+    send_command(Command::Hide);
+}
+
+/// Deletes the current notification from the DB before advancing -- the
+/// Wayland analog of `macos::handle_dismiss_delete`, minus the tray-icon/
+/// unread-count emit that needs an `AppHandle` this backend doesn't keep a
+/// copy of (see the module doc comment on reduced v1 scope).
+fn dismiss_delete() {
+    let Some(state_mutex) = TOAST_STATE.get() else {
+        return;
+    };
+    let notification = {
+        let state = match state_mutex.lock() {
+            Ok(s) => s,
+            Err(_) => return,
+        };
+        state.queue.get(state.current_index).cloned()
+    };
+    if let Some(n) = notification {
+        if !n.force_focus {
+            if let Ok(conn) = agentoast_shared::db::open_reader(&config::db_path()) {
+                let _ = agentoast_shared::db::delete_notification(&conn, n.id);
+            }
+        }
+    }
+    advance_or_hide();
+}
+
+fn send_command(cmd: Command) {
+    if let Some(tx) = COMMAND_TX.get() {
+        let _ = tx.send(cmd);
+    }
+}
+
+/// Schedules `advance_or_hide` after `duration_ms`, tagged with the current
+/// generation so a later `show_notifications`/`hide` makes it a no-op.
+fn start_timer(duration_ms: u64) {
+    let generation = TIMER_GENERATION.load(Ordering::SeqCst);
+    std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(duration_ms));
+        if TIMER_GENERATION.load(Ordering::SeqCst) == generation {
+            advance_or_hide();
+        }
+    });
+}
+
+fn advance_or_hide() {
+    let Some(state_mutex) = TOAST_STATE.get() else {
+        return;
+    };
+    let has_next = {
+        let mut state = match state_mutex.lock() {
+            Ok(s) => s,
+            Err(_) => return,
+        };
+        state.current_index += 1;
+        state.current_index < state.queue.len()
+    };
+
+    if has_next {
+        TIMER_GENERATION.fetch_add(1, Ordering::SeqCst);
+        send_command(Command::Render);
+    } else {
+        hide();
+    }
+}
+
+// --- Compositor connection + layer-surface rendering ---
+
+struct AppData {
+    registry_state: RegistryState,
+    seat_state: SeatState,
+    output_state: OutputState,
+    shm: Shm,
+    pool: SlotPool,
+    layer: LayerSurface,
+    pointer: Option<wl_pointer::WlPointer>,
+    width: u32,
+    height: u32,
+    configured: bool,
+}
+
+fn run_compositor_thread(rx: std::sync::mpsc::Receiver<Command>) {
+    let conn = match Connection::connect_to_env() {
+        Ok(c) => c,
+        Err(e) => {
+            log::error!("[native_toast] failed to connect to Wayland display: {e}");
+            return;
+        }
+    };
+
+    let (globals, mut event_queue) = match registry_queue_init(&conn) {
+        Ok(v) => v,
+        Err(e) => {
+            log::error!("[native_toast] registry_queue_init failed: {e}");
+            return;
+        }
+    };
+    let qh: QueueHandle<AppData> = event_queue.handle();
+
+    let compositor = match CompositorState::bind(&globals, &qh) {
+        Ok(c) => c,
+        Err(e) => {
+            log::error!("[native_toast] compositor not available: {e}");
+            return;
+        }
+    };
+    let layer_shell = match LayerShell::bind(&globals, &qh) {
+        Ok(l) => l,
+        Err(e) => {
+            log::error!("[native_toast] compositor has no wlr-layer-shell: {e}");
+            return;
+        }
+    };
+    let shm = match Shm::bind(&globals, &qh) {
+        Ok(s) => s,
+        Err(e) => {
+            log::error!("[native_toast] wl_shm not available: {e}");
+            return;
+        }
+    };
+
+    let surface = compositor.create_surface(&qh);
+    let layer = layer_shell.create_layer_surface(
+        &qh,
+        surface,
+        Layer::Overlay,
+        Some("agentoast-toast"),
+        None,
+    );
+    let cfg = config::load_config();
+    let (wl_anchor, margin_top, margin_right, margin_bottom, margin_left) = match cfg.toast.anchor
+    {
+        config::ToastAnchor::TopLeft => (
+            Anchor::TOP | Anchor::LEFT,
+            cfg.toast.margin_y as i32,
+            0,
+            0,
+            cfg.toast.margin_x as i32,
+        ),
+        config::ToastAnchor::TopRight => (
+            Anchor::TOP | Anchor::RIGHT,
+            cfg.toast.margin_y as i32,
+            cfg.toast.margin_x as i32,
+            0,
+            0,
+        ),
+        config::ToastAnchor::BottomLeft => (
+            Anchor::BOTTOM | Anchor::LEFT,
+            0,
+            0,
+            cfg.toast.margin_y as i32,
+            cfg.toast.margin_x as i32,
+        ),
+        config::ToastAnchor::BottomRight => (
+            Anchor::BOTTOM | Anchor::RIGHT,
+            0,
+            cfg.toast.margin_x as i32,
+            cfg.toast.margin_y as i32,
+            0,
+        ),
+    };
+    layer.set_anchor(wl_anchor);
+    layer.set_margin(margin_top, margin_right, margin_bottom, margin_left);
+    layer.set_keyboard_interactivity(KeyboardInteractivity::None);
+    layer.set_size(PANEL_WIDTH as u32, 144);
+    layer.commit();
+
+    let pool = match SlotPool::new((PANEL_WIDTH as usize) * 600 * 4, &shm) {
+        Ok(p) => p,
+        Err(e) => {
+            log::error!("[native_toast] failed to create shm pool: {e}");
+            return;
+        }
+    };
+
+    let mut data = AppData {
+        registry_state: RegistryState::new(&globals),
+        seat_state: SeatState::new(&globals, &qh),
+        output_state: OutputState::new(&globals, &qh),
+        shm,
+        pool,
+        layer,
+        pointer: None,
+        width: PANEL_WIDTH as u32,
+        height: 144,
+        configured: false,
+    };
+
+    loop {
+        if event_queue.blocking_dispatch(&mut data).is_err() {
+            log::info!("[native_toast] Wayland connection closed");
+            return;
+        }
+
+        while let Ok(cmd) = rx.try_recv() {
+            match cmd {
+                Command::Render => render(&mut data, &qh),
+                Command::Hide => {
+                    data.layer.wl_surface().attach(None, 0, 0);
+                    data.layer.commit();
+                }
+            }
+        }
+    }
+}
+
+fn render(data: &mut AppData, _qh: &QueueHandle<AppData>) {
+    let Some(state_mutex) = TOAST_STATE.get() else {
+        return;
+    };
+    let state = match state_mutex.lock() {
+        Ok(s) => s,
+        Err(_) => return,
+    };
+    let Some(current) = state.queue.get(state.current_index).cloned() else {
+        return;
+    };
+    let queue_len = state.queue.len();
+    let current_index = state.current_index;
+    let duration_ms = state.duration_ms;
+    let persistent = state.persistent;
+    drop(state);
+
+    let is_dark = is_dark_mode();
+    let colors = shared::colors(is_dark);
+
+    let has_meta =
+        !current.tmux_pane.is_empty() || current.metadata.values().any(|v| !v.is_empty());
+    let body_lines = if current.body.is_empty() {
+        0
+    } else {
+        shared::wrapped_line_count(&current.body, BODY_WIDTH, MAX_BODY_LINES, &|s| {
+            measure_text_width(s, 11.0)
+        })
+    };
+    let height = shared::compute_panel_height(has_meta, body_lines).round() as u32;
+    let width = PANEL_WIDTH as u32;
+
+    data.width = width;
+    data.height = height;
+    data.layer.set_size(width, height);
+    data.layer.commit();
+
+    let Ok((buffer, canvas)) = data.pool.create_buffer(
+        width as i32,
+        height as i32,
+        width as i32 * 4,
+        wayland_client::protocol::wl_shm::Format::Argb8888,
+    ) else {
+        return;
+    };
+
+    draw_toast(canvas, width, height, &colors, &current, current_index, queue_len, body_lines);
+
+    let wl_surface = data.layer.wl_surface();
+    let _ = buffer.attach_to(wl_surface);
+    wl_surface.damage_buffer(0, 0, width as i32, height as i32);
+    wl_surface.commit();
+
+    if !persistent {
+        start_timer(duration_ms);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn draw_toast(
+    canvas: &mut [u8],
+    width: u32,
+    height: u32,
+    colors: &shared::ToastColors,
+    notification: &Notification,
+    current_index: usize,
+    queue_len: usize,
+    body_lines: usize,
+) {
+    let Ok(surface) = ImageSurface::create_for_data(
+        canvas.to_vec(),
+        Format::ARgb32,
+        width as i32,
+        height as i32,
+        width as i32 * 4,
+    ) else {
+        return;
+    };
+    let Ok(cr) = CairoContext::new(&surface) else {
+        return;
+    };
+
+    // Background + border, rounded to match macOS's CORNER_RADIUS.
+    rounded_rect(&cr, 0.0, 0.0, width as f64, height as f64, shared::CORNER_RADIUS);
+    set_source_rgba(&cr, colors.bg);
+    let _ = cr.fill_preserve();
+    set_source_rgba(&cr, colors.border);
+    cr.set_line_width(1.0);
+    let _ = cr.stroke();
+
+    let pad = shared::PADDING + 4.0;
+    let mut y = shared::PADDING + shared::TOP_MARGIN;
+
+    // Line 1: repo name + relative time.
+    set_source_rgba(&cr, colors.text_secondary);
+    draw_text(&cr, &notification.repo, pad, y, 12.0, true);
+    let time_text = shared::format_relative_time(&notification.created_at);
+    if !time_text.is_empty() {
+        set_source_rgba(&cr, colors.text_muted);
+        let time_w = measure_text_width(&time_text, 10.0);
+        draw_text(&cr, &time_text, width as f64 - pad - time_w, y + 1.0, 10.0, false);
+    }
+    y += shared::LINE1_HEIGHT + shared::LINE_GAP;
+
+    // Line 2: tmux pane, as plain muted text (no icons, and no other
+    // metadata entries, in v1).
+    if !notification.tmux_pane.is_empty() {
+        set_source_rgba(&cr, colors.text_muted);
+        draw_text(&cr, &notification.tmux_pane, pad, y, 11.0, false);
+        y += shared::META_HEIGHT + shared::LINE_GAP;
+    }
+
+    // Body, wrapped at whatever `wrapped_line_count` already determined.
+    if body_lines > 0 {
+        set_source_rgba(&cr, colors.text_secondary);
+        let layout = create_layout(&cr);
+        layout.set_width((shared::BODY_WIDTH * pango::SCALE as f64) as i32);
+        layout.set_wrap(pango::WrapMode::Word);
+        layout.set_text(&notification.body);
+        cr.move_to(pad, y);
+        show_layout(&cr, &layout);
+    }
+
+    if queue_len > 1 {
+        let counter = format!("{}/{}", current_index + 1, queue_len);
+        set_source_rgba(&cr, colors.text_muted);
+        let counter_w = measure_text_width(&counter, 10.0);
+        draw_text(
+            &cr,
+            &counter,
+            width as f64 - pad - counter_w,
+            height as f64 - shared::PADDING - shared::BOTTOM_SECTION_H,
+            10.0,
+            false,
+        );
+    }
+
+    drop(cr);
+    let data = surface.take_data().unwrap_or_default();
+    canvas[..data.len()].copy_from_slice(&data);
+}
+
+fn rounded_rect(cr: &CairoContext, x: f64, y: f64, w: f64, h: f64, r: f64) {
+    cr.new_sub_path();
+    cr.arc(x + w - r, y + r, r, -std::f64::consts::FRAC_PI_2, 0.0);
+    cr.arc(x + w - r, y + h - r, r, 0.0, std::f64::consts::FRAC_PI_2);
+    cr.arc(x + r, y + h - r, r, std::f64::consts::FRAC_PI_2, std::f64::consts::PI);
+    cr.arc(x + r, y + r, r, std::f64::consts::PI, 3.0 * std::f64::consts::FRAC_PI_2);
+    cr.close_path();
+}
+
+fn set_source_rgba(cr: &CairoContext, color: (f64, f64, f64, f64)) {
+    cr.set_source_rgba(color.0, color.1, color.2, color.3);
+}
+
+fn draw_text(cr: &CairoContext, text: &str, x: f64, y: f64, size_pt: f64, bold: bool) {
+    let layout = create_layout(cr);
+    let mut desc = pango::FontDescription::new();
+    desc.set_family("sans-serif");
+    desc.set_size((size_pt * pango::SCALE as f64) as i32);
+    if bold {
+        desc.set_weight(pango::Weight::Medium);
+    }
+    layout.set_font_description(Some(&desc));
+    layout.set_text(text);
+    cr.move_to(x, y);
+    show_layout(cr, &layout);
+}
+
+fn measure_text_width(text: &str, size_pt: f64) -> f64 {
+    if text.is_empty() {
+        return 0.0;
+    }
+    let Ok(surface) = ImageSurface::create(Format::ARgb32, 1, 1) else {
+        return 0.0;
+    };
+    let Ok(cr) = CairoContext::new(&surface) else {
+        return 0.0;
+    };
+    let layout = create_layout(&cr);
+    let mut desc = pango::FontDescription::new();
+    desc.set_family("sans-serif");
+    desc.set_size((size_pt * pango::SCALE as f64) as i32);
+    layout.set_font_description(Some(&desc));
+    layout.set_text(text);
+    layout.pixel_size().0 as f64
+}
+
+/// Heuristic dark-mode detection: the `org.freedesktop.appearance`
+/// color-scheme portal is the correct source but pulls in a D-Bus
+/// dependency this crate doesn't otherwise need; `$COLOR_SCHEME` (exported
+/// directly by some compositors/session managers) is checked instead as a
+/// zero-dependency approximation, defaulting to light.
+fn is_dark_mode() -> bool {
+    std::env::var("COLOR_SCHEME")
+        .map(|v| v.eq_ignore_ascii_case("dark") || v.eq_ignore_ascii_case("prefer-dark"))
+        .unwrap_or(false)
+}
+
+impl CompositorHandler for AppData {
+    fn scale_factor_changed(
+        &mut self,
+        _: &Connection,
+        _: &QueueHandle<Self>,
+        _: &wl_surface::WlSurface,
+        _: i32,
+    ) {
+    }
+
+    fn transform_changed(
+        &mut self,
+        _: &Connection,
+        _: &QueueHandle<Self>,
+        _: &wl_surface::WlSurface,
+        _: wl_output::Transform,
+    ) {
+    }
+
+    fn frame(
+        &mut self,
+        _: &Connection,
+        _: &QueueHandle<Self>,
+        _: &wl_surface::WlSurface,
+        _: u32,
+    ) {
+    }
+
+    fn surface_enter(&mut self, _: &Connection, _: &QueueHandle<Self>, _: &wl_surface::WlSurface, _: &wl_output::WlOutput) {}
+    fn surface_leave(&mut self, _: &Connection, _: &QueueHandle<Self>, _: &wl_surface::WlSurface, _: &wl_output::WlOutput) {}
+}
+
+impl OutputHandler for AppData {
+    fn output_state(&mut self) -> &mut OutputState {
+        &mut self.output_state
+    }
+    fn new_output(&mut self, _: &Connection, _: &QueueHandle<Self>, _: wl_output::WlOutput) {}
+    fn update_output(&mut self, _: &Connection, _: &QueueHandle<Self>, _: wl_output::WlOutput) {}
+    fn output_destroyed(&mut self, _: &Connection, _: &QueueHandle<Self>, _: wl_output::WlOutput) {}
+}
+
+impl LayerShellHandler for AppData {
+    fn closed(&mut self, _: &Connection, _: &QueueHandle<Self>, _: &LayerSurface) {}
+
+    fn configure(
+        &mut self,
+        _: &Connection,
+        _: &QueueHandle<Self>,
+        _: &LayerSurface,
+        configure: LayerSurfaceConfigure,
+    ) {
+        self.configured = true;
+        if configure.new_size.0 > 0 {
+            self.width = configure.new_size.0;
+        }
+        if configure.new_size.1 > 0 {
+            self.height = configure.new_size.1;
+        }
+    }
+}
+
+impl SeatHandler for AppData {
+    fn seat_state(&mut self) -> &mut SeatState {
+        &mut self.seat_state
+    }
+
+    fn new_seat(&mut self, _: &Connection, _: &QueueHandle<Self>, _: wl_seat::WlSeat) {}
+
+    fn new_capability(
+        &mut self,
+        _: &Connection,
+        qh: &QueueHandle<Self>,
+        seat: wl_seat::WlSeat,
+        capability: Capability,
+    ) {
+        if capability == Capability::Pointer && self.pointer.is_none() {
+            self.pointer = self.seat_state.get_pointer(qh, &seat).ok();
+        }
+    }
+
+    fn remove_capability(
+        &mut self,
+        _: &Connection,
+        _: &QueueHandle<Self>,
+        _: wl_seat::WlSeat,
+        capability: Capability,
+    ) {
+        if capability == Capability::Pointer {
+            self.pointer = None;
+        }
+    }
+
+    fn remove_seat(&mut self, _: &Connection, _: &QueueHandle<Self>, _: wl_seat::WlSeat) {}
+}
+
+impl PointerHandler for AppData {
+    fn pointer_frame(
+        &mut self,
+        _: &Connection,
+        _: &QueueHandle<Self>,
+        _: &wl_pointer::WlPointer,
+        events: &[PointerEvent],
+    ) {
+        // v1: any press anywhere on the toast advances to the next queued
+        // notification (or hides if this was the last one). No dismiss-vs-
+        // focus distinction yet -- see the module doc comment.
+        for event in events {
+            if matches!(event.kind, PointerEventKind::Press { .. }) {
+                advance_or_hide();
+            }
+        }
+    }
+}
+
+impl ShmHandler for AppData {
+    fn shm_state(&mut self) -> &mut Shm {
+        &mut self.shm
+    }
+}
+
+impl ProvidesRegistryState for AppData {
+    fn registry(&mut self) -> &mut RegistryState {
+        &mut self.registry_state
+    }
+    registry_handlers![OutputState, SeatState];
+}
+
+delegate_compositor!(AppData);
+delegate_output!(AppData);
+delegate_shm!(AppData);
+delegate_seat!(AppData);
+delegate_pointer!(AppData);
+delegate_layer!(AppData);
+delegate_registry!(AppData);
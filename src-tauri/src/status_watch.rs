@@ -0,0 +1,160 @@
+//! Diffs each tmux pane's `AgentStatus` against its previous poll and fires a
+//! configured notification on meaningful transitions (Running→Waiting,
+//! Running→Idle), modeled after dunst's per-urgency rule matching: the first
+//! rule whose transition and optional `agent_type`/`repo_name`/`agent_mode`
+//! filters match wins.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+use agentoast_shared::config;
+use agentoast_shared::config::StatusTransitionRule;
+use agentoast_shared::models::{AgentStatus, IconType, TmuxPane};
+use agentoast_shared::notifier::{self, DesktopNotifier, NotificationPayload};
+use tauri::AppHandle;
+
+use crate::sessions;
+
+static LAST_STATUS: OnceLock<Mutex<HashMap<String, AgentStatus>>> = OnceLock::new();
+
+fn last_status_map() -> &'static Mutex<HashMap<String, AgentStatus>> {
+    LAST_STATUS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+pub fn start(_app_handle: AppHandle, interval_secs: u64) {
+    if interval_secs == 0 {
+        log::info!("[status_watch] disabled (poll_interval_secs = 0)");
+        return;
+    }
+
+    std::thread::spawn(move || loop {
+        std::thread::sleep(Duration::from_secs(interval_secs));
+        poll_once();
+    });
+}
+
+fn poll_once() {
+    let cfg = config::load_config().notification.status_rules;
+    if !cfg.enabled || cfg.rules.is_empty() {
+        return;
+    }
+
+    let groups = match sessions::list_tmux_panes_grouped() {
+        Ok(g) => g,
+        Err(e) => {
+            log::debug!("[status_watch] list_tmux_panes_grouped failed: {}", e);
+            return;
+        }
+    };
+
+    let mut last = last_status_map().lock().unwrap();
+
+    for group in &groups {
+        for pane in &group.panes {
+            let Some(status) = pane.agent_status else {
+                continue;
+            };
+            let previous = last.insert(pane.pane_id.clone(), status);
+
+            let transition = match (previous, status) {
+                (Some(AgentStatus::Running), AgentStatus::Waiting) => Some("running_to_waiting"),
+                (Some(AgentStatus::Running), AgentStatus::Idle) => Some("running_to_idle"),
+                _ => None,
+            };
+
+            let Some(transition) = transition else {
+                continue;
+            };
+
+            if let Some(rule) = find_matching_rule(&cfg.rules, transition, pane, &group.repo_name)
+            {
+                fire_rule(rule, pane, &group.repo_name);
+            }
+        }
+    }
+}
+
+fn find_matching_rule<'a>(
+    rules: &'a [StatusTransitionRule],
+    transition: &str,
+    pane: &TmuxPane,
+    repo_name: &str,
+) -> Option<&'a StatusTransitionRule> {
+    rules.iter().find(|rule| {
+        rule.transition == transition
+            && rule
+                .agent_type
+                .as_deref()
+                .map(|want| pane.agent_type.as_deref() == Some(want))
+                .unwrap_or(true)
+            && rule
+                .repo_name
+                .as_deref()
+                .map(|want| want == repo_name)
+                .unwrap_or(true)
+            && rule
+                .agent_mode
+                .as_deref()
+                .map(|want| pane.agent_modes.iter().any(|m| m == want))
+                .unwrap_or(true)
+    })
+}
+
+fn render_template(template: &str, pane: &TmuxPane, repo_name: &str) -> String {
+    template
+        .replace("{agent_type}", pane.agent_type.as_deref().unwrap_or(""))
+        .replace("{repo_name}", repo_name)
+        .replace("{branch}", pane.git_branch.as_deref().unwrap_or(""))
+        .replace("{window_name}", &pane.window_name)
+}
+
+fn fire_rule(rule: &StatusTransitionRule, pane: &TmuxPane, repo_name: &str) {
+    let summary = render_template(&rule.summary, pane, repo_name);
+    log::info!(
+        "[status_watch] {} urgency={} pane={} -> {}",
+        rule.transition,
+        rule.urgency,
+        pane.pane_id,
+        summary
+    );
+
+    let icon = IconType::Agentoast;
+    let payload = NotificationPayload {
+        badge: &summary,
+        body: &pane.window_name,
+        badge_color: urgency_color(&rule.urgency),
+        icon: &icon,
+        repo_name,
+        force_focus: false,
+    };
+    notifier::dispatch(&[Box::new(DesktopNotifier)], &payload);
+
+    if let Some(sound_cmd) = &rule.sound {
+        let cmd = render_template(sound_cmd, pane, repo_name).replace("{urgency}", &rule.urgency);
+        spawn_sound_command(&cmd);
+    }
+}
+
+fn urgency_color(urgency: &str) -> &'static str {
+    match urgency {
+        "critical" => "red",
+        "low" => "gray",
+        _ => "yellow",
+    }
+}
+
+/// Runs the configured sound command detached via the shell, fire-and-forget
+/// so a slow or hanging player never blocks the poll loop.
+fn spawn_sound_command(cmd: &str) {
+    if cmd.trim().is_empty() {
+        return;
+    }
+    if let Err(e) = std::process::Command::new("/bin/sh")
+        .arg("-c")
+        .arg(cmd)
+        .spawn()
+    {
+        log::warn!("[status_watch] failed to spawn sound command: {}", e);
+    }
+}
@@ -1,5 +1,21 @@
 use std::path::PathBuf;
 use std::process::Command;
+use std::sync::{Mutex, OnceLock};
+
+/// Maximum locations [`focus_terminal`] remembers for [`focus_previous`],
+/// bounding memory for a session that jumps around a lot without ever
+/// popping back.
+const FOCUS_HISTORY_MAX: usize = 20;
+
+struct FocusLocation {
+    tmux_pane: String,
+    terminal_bundle_id: String,
+}
+
+fn focus_history() -> &'static Mutex<Vec<FocusLocation>> {
+    static FOCUS_HISTORY: OnceLock<Mutex<Vec<FocusLocation>>> = OnceLock::new();
+    FOCUS_HISTORY.get_or_init(|| Mutex::new(Vec::new()))
+}
 
 const KNOWN_TERMINAL_BUNDLE_IDS: &[&str] = &[
     "com.github.wez.wezterm",
@@ -214,7 +230,66 @@ fn activate_any_terminal() -> Result<(), String> {
     Err("No known terminal application found".to_string())
 }
 
+/// Bundle ID of the frontmost application, or empty if it can't be
+/// determined (e.g. no app currently active).
+fn frontmost_bundle_id() -> String {
+    use objc2_app_kit::NSWorkspace;
+
+    let workspace = NSWorkspace::sharedWorkspace();
+    workspace
+        .frontmostApplication()
+        .and_then(|app| app.bundleIdentifier())
+        .map(|bid| bid.to_string())
+        .unwrap_or_default()
+}
+
+/// Tmux pane id of the currently attached client's active pane, or empty if
+/// tmux isn't installed or there's no attached client.
+fn current_tmux_pane() -> String {
+    let Some(tmux_path) = find_tmux() else {
+        return String::new();
+    };
+
+    Command::new(&tmux_path)
+        .env_remove("TMPDIR")
+        .args(["display-message", "-p", "#{pane_id}"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+        .unwrap_or_default()
+}
+
 pub fn focus_terminal(tmux_pane: &str, terminal_bundle_id: &str) -> Result<(), String> {
+    focus_terminal_impl(tmux_pane, terminal_bundle_id, true)
+}
+
+/// Shared by [`focus_terminal`] and [`focus_previous`]: switches to
+/// `tmux_pane`/`terminal_bundle_id`, recording where the user was beforehand
+/// only when `push_history` is set. `focus_previous` passes `false` since
+/// it's already popping that location off the stack -- pushing it back on
+/// would undo its own pop and mean "go back" can never walk further than one
+/// step.
+fn focus_terminal_impl(
+    tmux_pane: &str,
+    terminal_bundle_id: &str,
+    push_history: bool,
+) -> Result<(), String> {
+    if push_history {
+        // Remember where the user was before we jump away, so focus_previous()
+        // can pop straight back to it.
+        let previous = FocusLocation {
+            tmux_pane: current_tmux_pane(),
+            terminal_bundle_id: frontmost_bundle_id(),
+        };
+        let mut history = focus_history().lock().unwrap();
+        history.push(previous);
+        if history.len() > FOCUS_HISTORY_MAX {
+            history.remove(0);
+        }
+        drop(history);
+    }
+
     // 1. Switch tmux pane if specified (failure is non-fatal)
     if !tmux_pane.is_empty() {
         if let Err(e) = switch_tmux_pane(tmux_pane) {
@@ -229,3 +304,16 @@ pub fn focus_terminal(tmux_pane: &str, terminal_bundle_id: &str) -> Result<(), S
         activate_terminal(terminal_bundle_id)
     }
 }
+
+/// Pops the last location recorded by [`focus_terminal`] and re-focuses it,
+/// so a user can triage a notification's pane then jump straight back to
+/// wherever they were before.
+pub fn focus_previous() -> Result<(), String> {
+    let previous = focus_history().lock().unwrap().pop();
+    match previous {
+        Some(location) => {
+            focus_terminal_impl(&location.tmux_pane, &location.terminal_bundle_id, false)
+        }
+        None => Err("No previous focus location recorded".to_string()),
+    }
+}
@@ -37,13 +37,17 @@ pub fn init(app_handle: &tauri::AppHandle) -> tauri::Result<()> {
     panel.set_opaque(false);
     panel.set_level(PanelLevel::Floating.value() + 2);
 
-    panel.set_collection_behavior(
-        CollectionBehavior::new()
-            .can_join_all_spaces()
-            .stationary()
-            .full_screen_auxiliary()
-            .value(),
-    );
+    let visible_on_all_workspaces = app_handle
+        .state::<std::sync::Mutex<crate::AppState>>()
+        .lock()
+        .map(|s| s.config.toast.visible_on_all_workspaces)
+        .unwrap_or(true);
+
+    let mut behavior = CollectionBehavior::new().stationary().full_screen_auxiliary();
+    if visible_on_all_workspaces {
+        behavior = behavior.can_join_all_spaces();
+    }
+    panel.set_collection_behavior(behavior.value());
 
     panel.set_style_mask(StyleMask::empty().nonactivating_panel().value());
     log::info!("[toast] init complete");
@@ -1,7 +1,8 @@
 use std::sync::OnceLock;
 
+use agentoast_shared::models::AgentStatus;
 use tauri::image::Image;
-use tauri::menu::{Menu, MenuItem, PredefinedMenuItem};
+use tauri::menu::{Menu, MenuItem, PredefinedMenuItem, Submenu};
 use tauri::path::BaseDirectory;
 use tauri::tray::{MouseButtonState, TrayIconBuilder, TrayIconEvent};
 use tauri::{AppHandle, Emitter, Manager};
@@ -10,6 +11,17 @@ use tauri_nspanel::ManagerExt;
 use crate::panel::position_panel_at_tray_icon;
 
 static MUTE_MENU_ITEM: OnceLock<MenuItem<tauri::Wry>> = OnceLock::new();
+static AGENTS_SUBMENU: OnceLock<Submenu<tauri::Wry>> = OnceLock::new();
+static UPDATE_MENU_ITEM: OnceLock<MenuItem<tauri::Wry>> = OnceLock::new();
+
+/// One pane worth of activity, trimmed to what the tray needs to render a
+/// submenu entry ("repo · window — status") and its focus action.
+pub struct PaneActivity {
+    pub pane_id: String,
+    pub repo_name: String,
+    pub window_name: String,
+    pub status: AgentStatus,
+}
 
 macro_rules! get_or_init_panel {
     ($app_handle:expr) => {
@@ -62,10 +74,47 @@ pub fn create(app_handle: &AppHandle) -> tauri::Result<()> {
     let mute = MenuItem::with_id(app_handle, "mute", "Mute Notifications", true, None::<&str>)?;
     let _ = MUTE_MENU_ITEM.set(mute.clone());
     let clear_all = MenuItem::with_id(app_handle, "clear_all", "Clear All", true, None::<&str>)?;
+    let check_updates = MenuItem::with_id(
+        app_handle,
+        "check_updates",
+        "Check for Updates…",
+        true,
+        None::<&str>,
+    )?;
+    let install_update = MenuItem::with_id(
+        app_handle,
+        "install_update",
+        "No update available",
+        false,
+        None::<&str>,
+    )?;
+    let _ = UPDATE_MENU_ITEM.set(install_update.clone());
     let separator = PredefinedMenuItem::separator(app_handle)?;
     let quit = MenuItem::with_id(app_handle, "quit", "Quit", true, None::<&str>)?;
 
-    let menu = Menu::with_items(app_handle, &[&show, &mute, &clear_all, &separator, &quit])?;
+    let no_agents = MenuItem::with_id(
+        app_handle,
+        "no_active_agents",
+        "No active agents",
+        false,
+        None::<&str>,
+    )?;
+    let agents_submenu = Submenu::with_items(app_handle, "Agents", true, &[&no_agents])?;
+    let _ = AGENTS_SUBMENU.set(agents_submenu.clone());
+
+    let menu = Menu::with_items(
+        app_handle,
+        &[
+            &show,
+            &mute,
+            &clear_all,
+            &agents_submenu,
+            &check_updates,
+            &install_update,
+            &separator,
+            &quit,
+        ],
+    )?;
 
     TrayIconBuilder::with_id("tray")
         .icon(icon)
@@ -90,11 +139,25 @@ pub fn create(app_handle: &AppHandle) -> tauri::Result<()> {
                 let _ = app_handle.emit("notifications:refresh", ());
                 let _ = app_handle.emit("notifications:unread-count", 0i64);
                 crate::watcher::update_tray_icon(app_handle, 0);
+                crate::watcher::wake();
+            }
+            "check_updates" => {
+                crate::updater::check_now(app_handle.clone());
+            }
+            "install_update" => {
+                crate::updater::install_pending(app_handle.clone());
             }
             "quit" => {
                 app_handle.exit(0);
             }
-            _ => {}
+            "no_active_agents" => {}
+            id => {
+                if let Some(pane_id) = id.strip_prefix("focus_pane:") {
+                    if let Err(e) = crate::terminal::focus_terminal(pane_id, "") {
+                        log::debug!("Failed to focus pane {}: {}", pane_id, e);
+                    }
+                }
+            }
         })
         .on_tray_icon_event(|tray, event| {
             let app_handle = tray.app_handle();
@@ -136,3 +199,114 @@ pub fn update_mute_menu(_app_handle: &AppHandle, global_muted: bool) {
         }
     }
 }
+
+/// Enables the tray's "Install Update" item and relabels it with the
+/// available version, so picking it up doesn't require opening the panel.
+/// Called from `updater::check_now` once a newer version is confirmed.
+pub fn set_update_available(_app_handle: &AppHandle, version: &str) {
+    if let Some(item) = UPDATE_MENU_ITEM.get() {
+        let _ = item.set_text(format!("Install Update ({})", version));
+        let _ = item.set_enabled(true);
+    }
+}
+
+fn status_label(status: AgentStatus) -> &'static str {
+    match status {
+        AgentStatus::Running => "running",
+        AgentStatus::Waiting => "waiting",
+        AgentStatus::Idle => "idle",
+    }
+}
+
+/// Reflects the current set of agent panes in the tray icon, tooltip and
+/// "Agents" submenu: a spinner-style icon while any pane is `Running`, an
+/// attention icon when at least one is `Waiting`, and the normal unread-badge
+/// icon when every pane is idle (or there are no agent panes at all).
+pub fn update_activity(app_handle: &AppHandle, panes: &[PaneActivity]) {
+    let running = panes
+        .iter()
+        .filter(|p| p.status == AgentStatus::Running)
+        .count();
+    let waiting = panes
+        .iter()
+        .filter(|p| p.status == AgentStatus::Waiting)
+        .count();
+
+    if let Some(tray) = app_handle.tray_by_id(&tauri::tray::TrayIconId::new("tray")) {
+        if running > 0 || waiting > 0 {
+            let mut parts = Vec::new();
+            if running > 0 {
+                parts.push(format!("{} running", running));
+            }
+            if waiting > 0 {
+                parts.push(format!("{} waiting", waiting));
+            }
+            let _ = tray.set_tooltip(Some(&format!("Agentoast ({})", parts.join(", "))));
+
+            let icon_name = if waiting > 0 {
+                "icons/tray-icon-waiting.png"
+            } else {
+                "icons/tray-icon-running.png"
+            };
+            if let Ok(path) = app_handle.path().resolve(icon_name, BaseDirectory::Resource) {
+                if let Ok(icon) = Image::from_path(path) {
+                    let _ = tray.set_icon(Some(icon));
+                    let _ = tray.set_icon_as_template(false);
+                }
+            }
+        } else {
+            // Nothing active: defer to whatever the unread-count badge would
+            // show rather than overriding it with a separate "idle" icon.
+            let unread_count = agentoast_shared::db::open_reader(&agentoast_shared::config::db_path())
+                .ok()
+                .and_then(|conn| agentoast_shared::db::get_unread_count(&conn).ok())
+                .unwrap_or(0);
+            crate::watcher::update_tray_icon(app_handle, unread_count);
+        }
+    }
+
+    let Some(submenu) = AGENTS_SUBMENU.get() else {
+        return;
+    };
+
+    if panes.is_empty() {
+        let Ok(no_agents) = MenuItem::with_id(
+            app_handle,
+            "no_active_agents",
+            "No active agents",
+            false,
+            None::<&str>,
+        ) else {
+            return;
+        };
+        if let Err(e) = submenu.set_items(&[&no_agents]) {
+            log::debug!("Failed to reset agents submenu: {}", e);
+        }
+        return;
+    }
+
+    let items: Vec<MenuItem<tauri::Wry>> = panes
+        .iter()
+        .filter_map(|pane| {
+            let label = format!(
+                "{} · {} — {}",
+                pane.repo_name,
+                pane.window_name,
+                status_label(pane.status)
+            );
+            MenuItem::with_id(
+                app_handle,
+                format!("focus_pane:{}", pane.pane_id),
+                label,
+                true,
+                None::<&str>,
+            )
+            .ok()
+        })
+        .collect();
+
+    let refs: Vec<&MenuItem<tauri::Wry>> = items.iter().collect();
+    if let Err(e) = submenu.set_items(&refs) {
+        log::debug!("Failed to rebuild agents submenu: {}", e);
+    }
+}
@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use agentoast_shared::config;
+use agentoast_shared::models::IconType;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_updater::UpdaterExt;
+
+use crate::MuteState;
+
+/// Payload for the `update:available` event the panel front end renders.
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdateAvailablePayload {
+    version: String,
+    notes: Option<String>,
+}
+
+/// Checks the configured feed once. Mirrors `watcher::check_new_notifications`'s
+/// mute handling: a muted app only gets a tray badge, never an interrupting toast.
+pub fn check_now(app_handle: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let updater = match app_handle.updater() {
+            Ok(u) => u,
+            Err(e) => {
+                log::error!("[updater] failed to build updater: {}", e);
+                return;
+            }
+        };
+
+        match updater.check().await {
+            Ok(Some(update)) => {
+                log::info!("[updater] update available: {}", update.version);
+                badge_tray(&app_handle);
+                crate::tray::set_update_available(&app_handle, &update.version);
+
+                let is_muted = app_handle
+                    .state::<std::sync::Mutex<MuteState>>()
+                    .lock()
+                    .map(|s| s.global_muted)
+                    .unwrap_or(false);
+
+                if is_muted {
+                    log::info!("[updater] global mute active, suppressing update toast");
+                } else {
+                    let _ = app_handle.emit(
+                        "update:available",
+                        UpdateAvailablePayload {
+                            version: update.version.clone(),
+                            notes: update.body.clone(),
+                        },
+                    );
+                    notify_update_available(&update.version);
+                }
+            }
+            Ok(None) => log::info!("[updater] no update available"),
+            Err(e) => log::error!("[updater] check failed: {}", e),
+        }
+    });
+}
+
+/// Inserts a regular notification row for the new version so it rides the
+/// same toast/unread pipeline every other notification does (`watcher`'s
+/// file-watcher + poll loop, gated by the same mute checks), instead of a
+/// bespoke one-off toast path.
+fn notify_update_available(version: &str) {
+    let db_path = config::db_path();
+    let conn = match agentoast_shared::db::open(&db_path) {
+        Ok(c) => c,
+        Err(e) => {
+            log::error!("[updater] failed to open db for update notification: {}", e);
+            return;
+        }
+    };
+
+    let result = agentoast_shared::db::insert_notification(
+        &conn,
+        "Update available",
+        &format!("Agentoast {} is ready to install", version),
+        "yellow",
+        &IconType::Agentoast,
+        &HashMap::new(),
+        "",
+        "",
+        "",
+        false,
+    );
+
+    if let Err(e) = result {
+        log::error!("[updater] failed to insert update notification: {}", e);
+        return;
+    }
+
+    crate::watcher::wake();
+}
+
+fn badge_tray(app_handle: &AppHandle) {
+    if let Some(tray) = app_handle.tray_by_id(&tauri::tray::TrayIconId::new("tray")) {
+        let _ = tray.set_tooltip(Some("Agentoast (update available)"));
+    }
+}
+
+/// Re-checks the feed and, if an update is still there, downloads, stages
+/// and installs it, then relaunches the app. Triggered by the tray's
+/// dynamic "Install Update" item (`tray::set_update_available`).
+pub fn install_pending(app_handle: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let updater = match app_handle.updater() {
+            Ok(u) => u,
+            Err(e) => {
+                log::error!("[updater] failed to build updater for install: {}", e);
+                return;
+            }
+        };
+
+        match updater.check().await {
+            Ok(Some(update)) => {
+                log::info!("[updater] downloading and installing {}", update.version);
+                if let Err(e) = update.download_and_install(|_, _| {}, || {}).await {
+                    log::error!("[updater] download_and_install failed: {}", e);
+                    return;
+                }
+                app_handle.restart();
+            }
+            Ok(None) => log::info!("[updater] install requested but no update is available"),
+            Err(e) => log::error!("[updater] re-check before install failed: {}", e),
+        }
+    });
+}
+
+/// Spawns the periodic background check, on the same thread-plus-sleep
+/// infrastructure `watcher::start` uses for its polling fallback. A
+/// `auto_check_interval_secs` of 0 disables the loop; the tray's "Check for
+/// Updates…" item still triggers `check_now` manually.
+pub fn start_periodic(app_handle: AppHandle, interval_secs: u64) {
+    if interval_secs == 0 {
+        log::info!("[updater] auto_check disabled (interval_secs = 0)");
+        return;
+    }
+
+    std::thread::spawn(move || loop {
+        check_now(app_handle.clone());
+        std::thread::sleep(Duration::from_secs(interval_secs));
+    });
+}
@@ -1,11 +1,14 @@
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicI64, Ordering};
-use std::sync::Mutex;
-use std::time::{Duration, Instant};
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
 
+use agentoast_shared::config;
 use agentoast_shared::db;
 use agentoast_shared::db::Connection;
-use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use agentoast_shared::models::IconType;
+use agentoast_shared::notifier::{self, NotificationPayload};
+use crossbeam_channel::{tick, unbounded, Select, Sender};
 use tauri::image::Image;
 use tauri::path::BaseDirectory;
 use tauri::tray::TrayIconId;
@@ -16,6 +19,29 @@ use crate::MuteState;
 
 static LAST_KNOWN_ID: AtomicI64 = AtomicI64::new(0);
 
+/// Lets other parts of the app (e.g. the tray's "Clear All") pulse an
+/// immediate rescan out-of-band, without waiting on the debounce or the
+/// poll tick. Set once by [`start`]; a pulse before that is a silent no-op.
+static WAKE_TX: OnceLock<Sender<()>> = OnceLock::new();
+
+/// Forces the watcher loop to run [`check_new_notifications`] on its next
+/// iteration, bypassing the file-watcher debounce and the 5-second poll
+/// interval. Used when a caller already knows the DB changed.
+pub fn wake() {
+    if let Some(tx) = WAKE_TX.get() {
+        let _ = tx.send(());
+    }
+}
+
+/// Single multiplexed loop over every source that can indicate "the
+/// notifications DB may have changed": `db::watch`'s change feed (an
+/// in-process `update_hook` poke layered on a poll loop, replacing the old
+/// `notify` file watcher and its trailing-edge debounce), a 5-second poll
+/// tick of our own as a belt-and-suspenders fallback, the `wake()` channel
+/// for synchronous UI-triggered rescans, and the snooze re-surface tick. One
+/// shared reader connection and one `check_new_notifications` call per
+/// batch, regardless of which source fired, replaces what used to be two
+/// independent threads racing each other with their own connections.
 pub fn start(app_handle: AppHandle, db_path: PathBuf) {
     // Initialize last known ID
     if let Ok(conn) = db::open(&db_path) {
@@ -24,113 +50,51 @@ pub fn start(app_handle: AppHandle, db_path: PathBuf) {
         }
     }
 
-    let handle_for_fs = app_handle.clone();
-    let db_path_for_fs = db_path.clone();
+    let (wake_tx, wake_rx) = unbounded();
+    let _ = WAKE_TX.set(wake_tx);
+
+    let db_changes = db::watch(&db_path);
 
-    // File system watcher (trailing-edge debounce)
-    //
-    // Uses recv_timeout to wait 300ms after the last DB file event before checking.
-    // This ensures the check runs AFTER the CLI's transaction has committed,
-    // preventing the watcher from reading uncommitted WAL data and missing
-    // the new notification.
     std::thread::spawn(move || {
-        let conn = match db::open_reader(&db_path_for_fs) {
+        let conn = match db::open_reader(&db_path) {
             Ok(c) => c,
             Err(e) => {
-                log::error!("Failed to open DB for file watcher: {}", e);
+                log::error!("Failed to open DB for watcher: {}", e);
                 return;
             }
         };
 
-        let (tx, rx) = std::sync::mpsc::channel();
-
-        let mut watcher: RecommendedWatcher =
-            Watcher::new(tx, notify::Config::default()).expect("Failed to create file watcher");
-
-        // Watch the directory containing the DB file
-        if let Some(parent) = db_path_for_fs.parent() {
-            if let Err(e) = watcher.watch(parent, RecursiveMode::NonRecursive) {
-                log::error!("Failed to watch DB directory: {}", e);
-            }
-        }
-
-        let db_file_name = db_path_for_fs
-            .file_name()
-            .map(|n| n.to_string_lossy().to_string());
-
-        let debounce = Duration::from_millis(300);
-        let mut last_event: Option<Instant> = None;
+        let poll_tick = tick(Duration::from_secs(5));
+        let snooze_poll_secs = config::load_config().notification.snooze.poll_interval_secs.max(1);
+        let snooze_tick = tick(Duration::from_secs(snooze_poll_secs));
 
         loop {
-            let timeout = match last_event {
-                Some(t) => {
-                    let elapsed = t.elapsed();
-                    if elapsed >= debounce {
-                        check_new_notifications(&handle_for_fs, &conn, "file-watcher");
-                        last_event = None;
-                        Duration::from_secs(3600)
-                    } else {
-                        debounce - elapsed
-                    }
+            let mut select = Select::new();
+            let changes_op = select.recv(&db_changes);
+            let tick_op = select.recv(&poll_tick);
+            let wake_op = select.recv(&wake_rx);
+            let snooze_op = select.recv(&snooze_tick);
+
+            let op = select.select();
+            match op.index() {
+                i if i == changes_op => {
+                    let _ = op.recv(&db_changes);
+                    check_new_notifications(&app_handle, &conn, "db-watch");
                 }
-                None => Duration::from_secs(3600),
-            };
-
-            match rx.recv_timeout(timeout) {
-                Ok(Ok(event)) => {
-                    let is_db_event = match &db_file_name {
-                        Some(name) => event.paths.iter().any(|p| {
-                            p.file_name()
-                                .map(|n| {
-                                    let n = n.to_string_lossy();
-                                    n == name.as_str()
-                                        || n.starts_with(&format!("{}-", name))
-                                        || n == format!("{}-wal", name)
-                                        || n == format!("{}-shm", name)
-                                })
-                                .unwrap_or(false)
-                        }),
-                        None => false,
-                    };
-
-                    if is_db_event {
-                        match event.kind {
-                            EventKind::Create(_) | EventKind::Modify(_) => {
-                                last_event = Some(Instant::now());
-                            }
-                            _ => {}
-                        }
-                    }
+                i if i == tick_op => {
+                    let _ = op.recv(&poll_tick);
+                    check_new_notifications(&app_handle, &conn, "polling");
                 }
-                Ok(Err(e)) => {
-                    log::error!("File watch error: {}", e);
+                i if i == wake_op => {
+                    let _ = op.recv(&wake_rx);
+                    check_new_notifications(&app_handle, &conn, "wake");
                 }
-                Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {
-                    if last_event.is_some() {
-                        check_new_notifications(&handle_for_fs, &conn, "file-watcher");
-                        last_event = None;
-                    }
+                i if i == snooze_op => {
+                    let _ = op.recv(&snooze_tick);
+                    check_due_snoozes(&app_handle, &conn);
                 }
-                Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => break,
-            }
-        }
-    });
-
-    // Polling fallback (every 5 seconds)
-    let handle_for_poll = app_handle.clone();
-    let db_path_for_poll = db_path.clone();
-    std::thread::spawn(move || {
-        let conn = match db::open_reader(&db_path_for_poll) {
-            Ok(c) => c,
-            Err(e) => {
-                log::error!("Failed to open DB for polling: {}", e);
-                return;
+                _ => unreachable!("Select only registered four arms"),
             }
-        };
-
-        loop {
-            std::thread::sleep(Duration::from_secs(5));
-            check_new_notifications(&handle_for_poll, &conn, "polling");
         }
     });
 }
@@ -276,7 +240,7 @@ fn check_new_notifications(app_handle: &AppHandle, conn: &Connection, source: &s
         {
             if !n.tmux_pane.is_empty() {
                 if let Some(repo) = resolve_pane_repo(&n.tmux_pane) {
-                    return muted_repos.contains(&repo);
+                    return muted_repos.contains_key(&repo);
                 }
             }
         }
@@ -300,11 +264,15 @@ fn check_new_notifications(app_handle: &AppHandle, conn: &Connection, source: &s
         .collect();
 
     if !filtered_toast.is_empty() {
-        let _ = app_handle.emit_to("toast", "toast:show", &filtered_toast);
-        let handle = app_handle.clone();
-        let _ = app_handle.run_on_main_thread(move || {
-            toast::show(&handle);
-        });
+        if app_handle.get_webview_window("toast").is_some() {
+            let _ = app_handle.emit_to("toast", "toast:show", &filtered_toast);
+            let handle = app_handle.clone();
+            let _ = app_handle.run_on_main_thread(move || {
+                toast::show(&handle);
+            });
+        } else if config::load_config().notification.delivery.native {
+            notify_via_native_fallback(&filtered_toast);
+        }
     }
 
     // Emit notifications:new only for normal notifications (not force_focus)
@@ -349,6 +317,91 @@ fn check_new_notifications(app_handle: &AppHandle, conn: &Connection, source: &s
     }
 }
 
+/// Re-surfaces notifications whose `db::snooze_notification` timer has
+/// elapsed: clears their snooze (marking them unread again) and shows them
+/// the same way a brand-new notification would be shown. Unlike
+/// [`check_new_notifications`], this isn't gated on `LAST_KNOWN_ID` -- a
+/// snoozed notification's id is almost always older than the watermark, since
+/// it already existed before it was snoozed.
+fn check_due_snoozes(app_handle: &AppHandle, conn: &Connection) {
+    let now: String = match conn.query_row(
+        "SELECT strftime('%Y-%m-%dT%H:%M:%fZ', 'now')",
+        [],
+        |row| row.get(0),
+    ) {
+        Ok(now) => now,
+        Err(e) => {
+            log::error!("Failed to read current time for snooze check: {}", e);
+            return;
+        }
+    };
+
+    let due = match db::get_due_snoozed(conn, &now) {
+        Ok(n) => n,
+        Err(e) => {
+            log::error!("Failed to query due snoozes: {}", e);
+            return;
+        }
+    };
+
+    if due.is_empty() {
+        return;
+    }
+
+    for n in &due {
+        if let Err(e) = db::clear_snooze(conn, n.id) {
+            log::error!("Failed to clear snooze for notification {}: {}", n.id, e);
+        }
+    }
+
+    log::info!("Re-surfacing {} snoozed notification(s)", due.len());
+
+    let mute_state = app_handle.state::<Mutex<MuteState>>();
+    let is_global_muted = mute_state.lock().map(|m| m.global_muted).unwrap_or(false);
+
+    if !is_global_muted {
+        if app_handle.get_webview_window("toast").is_some() {
+            let _ = app_handle.emit_to("toast", "toast:show", &due);
+            let handle = app_handle.clone();
+            let _ = app_handle.run_on_main_thread(move || {
+                toast::show(&handle);
+            });
+        } else if config::load_config().notification.delivery.native {
+            notify_via_native_fallback(&due);
+        }
+        let _ = app_handle.emit("notifications:new", &due);
+    }
+
+    if let Ok(count) = db::get_unread_count(conn) {
+        let _ = app_handle.emit("notifications:unread-count", count);
+        update_tray_icon(app_handle, count);
+    }
+}
+
+/// Surfaces `notifications` through the OS notification center instead of the
+/// toast panel, for when the toast webview window doesn't exist (headless
+/// builds, or platforms other than macOS that `toast::show` doesn't support).
+/// Gated by `[notification.delivery] native`, separate from `desktop` (which
+/// fires unconditionally from the CLI hook path regardless of whether this
+/// GUI app is even running).
+fn notify_via_native_fallback(notifications: &[agentoast_shared::models::Notification]) {
+    let backends: Vec<Box<dyn notifier::Notifier>> = vec![Box::new(notifier::DesktopNotifier)];
+    for n in notifications {
+        let icon: IconType = n.icon.parse().unwrap_or(IconType::Agentoast);
+        notifier::dispatch(
+            &backends,
+            &NotificationPayload {
+                badge: &n.title,
+                body: &n.body,
+                badge_color: &n.color,
+                icon: &icon,
+                repo_name: &n.group_name,
+                force_focus: n.force_focus,
+            },
+        );
+    }
+}
+
 pub fn update_tray_icon(app_handle: &AppHandle, unread_count: i64) {
     if let Some(tray) = app_handle.tray_by_id(&TrayIconId::new("tray")) {
         let tooltip = if unread_count > 0 {